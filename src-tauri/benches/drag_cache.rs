@@ -0,0 +1,97 @@
+//! Compares what the drag hot path costs per mouse move when it reads the
+//! stores directly against reading the cached values.
+//!
+//! Run with `cargo bench --bench drag_cache`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crosszones_lib::bench::{Cached, Settings, ZoneLayout};
+use serde_json::{json, Value as JsonValue};
+
+const ITERATIONS: u32 = 100_000;
+
+/// A store with a realistic number of layouts, the last one active
+fn layouts_store() -> JsonValue {
+    let layouts: Vec<JsonValue> = (0..20)
+        .map(|layout| {
+            let zones: Vec<JsonValue> = (0..12)
+                .map(|zone| {
+                    json!({
+                        "id": format!("zone-{}-{}", layout, zone),
+                        "x": (zone % 4) as f64 * 25.0,
+                        "y": (zone / 4) as f64 * 33.3,
+                        "width": 25.0,
+                        "height": 33.3,
+                        "number": zone + 1,
+                    })
+                })
+                .collect();
+            json!({
+                "id": format!("layout-{}", layout),
+                "name": format!("Layout {}", layout),
+                "zones": zones,
+            })
+        })
+        .collect();
+    json!({ "layouts": layouts, "active_layout_id": "layout-19" })
+}
+
+fn settings_store() -> JsonValue {
+    json!({
+        "auto_start": true,
+        "zone_drag_modifier_key": "shift",
+        "show_zone_drag_overlay": true,
+        "zone_overlay_opacity": 0.25,
+    })
+}
+
+/// What `check_and_update_modifier_state` did before caching
+fn read_from_store(settings: &JsonValue, store: &JsonValue) -> (Settings, Option<ZoneLayout>) {
+    let settings: Settings = serde_json::from_value(settings.clone()).unwrap();
+    let active_id: String = serde_json::from_value(store["active_layout_id"].clone()).unwrap();
+    let layouts: Vec<ZoneLayout> = serde_json::from_value(store["layouts"].clone()).unwrap();
+    let layout = layouts.into_iter().find(|layout| layout.id == active_id);
+    (settings, layout)
+}
+
+fn measure(name: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up, which also fills the caches
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let per_iteration = start.elapsed() / ITERATIONS;
+    println!("{:<12} {:>10?} per mouse move", name, per_iteration);
+    per_iteration
+}
+
+fn main() {
+    let settings_json = settings_store();
+    let layouts_json = layouts_store();
+
+    let uncached = measure("store", || {
+        black_box(read_from_store(black_box(&settings_json), black_box(&layouts_json)));
+    });
+
+    let settings_cache: Cached<Settings> = Cached::new();
+    let layout_cache: Cached<Option<ZoneLayout>> = Cached::new();
+    let cached = measure("cache", || {
+        let settings = settings_cache
+            .get_or_load(|| Ok(read_from_store(&settings_json, &layouts_json).0))
+            .unwrap();
+        let layout = layout_cache
+            .get_or_load(|| Ok(read_from_store(&settings_json, &layouts_json).1))
+            .unwrap();
+        black_box((settings, layout));
+    });
+
+    println!(
+        "cache is {:.0}x faster",
+        uncached.as_secs_f64() / cached.as_secs_f64().max(f64::EPSILON)
+    );
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ScopeMode } from "./ScopeMode";
+
+/**
+ * Applications a hotkey is limited to or left out of
+ */
+export type AppScope = { mode: ScopeMode, 
+/**
+ * Executable names on Windows, e.g. `Code.exe`, and bundle ids on macOS, e.g.
+ * `com.microsoft.VSCode`
+ */
+apps: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImportIssue } from "./ImportIssue";
+
+export type BundleImportReport = { layouts: number, hotkeys: number, 
+/**
+ * Layout ids that collided with existing layouts, as `[old, new]` pairs
+ */
+remapped_layout_ids: Array<[string, string]>, issues: Array<ImportIssue>, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LayoutAction } from "./LayoutAction";
+
+/**
+ * Keys pressed one after the other following the leader key, and the actions they run
+ */
+export type ChordBinding = { 
+/**
+ * Key names as understood by `Shortcut::from_str`, e.g. `KeyH`
+ */
+sequence: Array<string>, actions: Array<LayoutAction>, };
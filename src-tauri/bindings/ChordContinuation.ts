@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A key that can be pressed next, shown in the hint window
+ */
+export type ChordContinuation = { key: string, 
+/**
+ * The key as printed on the keyboard, e.g. `H` for `KeyH`
+ */
+label: string, 
+/**
+ * Names of the actions this key runs, empty if more keys have to follow
+ */
+actions: Array<string>, };
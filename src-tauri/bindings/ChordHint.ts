@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChordContinuation } from "./ChordContinuation";
+
+export type ChordHint = { 
+/**
+ * Labels of the keys pressed since the leader
+ */
+pressed: Array<string>, continuations: Array<ChordContinuation>, };
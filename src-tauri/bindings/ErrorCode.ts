@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stable error codes for the frontend. Existing codes must never change.
+ */
+export type ErrorCode = "store" | "platform" | "permission" | "validation" | "not-found" | "conflict";
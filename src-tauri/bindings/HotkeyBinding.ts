@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LayoutAction } from "./LayoutAction";
+
+/**
+ * A shortcut and the actions it runs, in order
+ */
+export type HotkeyBinding = { shortcut: string, actions: Array<LayoutAction>, };
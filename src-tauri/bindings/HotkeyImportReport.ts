@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ImportIssue } from "./ImportIssue";
+import type { ImportedHotkey } from "./ImportedHotkey";
+
+export type HotkeyImportReport = { imported: Array<ImportedHotkey>, issues: Array<ImportIssue>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppScope } from "./AppScope";
+import type { WindowTarget } from "./WindowTarget";
+
+/**
+ * Per-binding settings that aren't part of the actions
+ */
+export type HotkeyOptions = { 
+/**
+ * Applications the hotkey is limited to or left out of. `None` works everywhere.
+ */
+scope: AppScope | null, target: WindowTarget, };
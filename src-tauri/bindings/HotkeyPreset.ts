@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HotkeyBinding } from "./HotkeyBinding";
+
+/**
+ * A built-in set of bindings, with the shortcuts for the current platform
+ */
+export type HotkeyPreset = { id: string, name: string, description: string, bindings: Array<HotkeyBinding>, };
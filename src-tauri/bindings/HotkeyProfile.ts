@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LayoutAction } from "./LayoutAction";
+
+/**
+ * A named set of hotkeys, e.g. for a laptop or a docked setup
+ */
+export type HotkeyProfile = { id: string, name: string, 
+/**
+ * Shortcut → actions. The active profile's hotkeys live in the hotkeys store and
+ * are only copied here when switching away from it.
+ */
+hotkeys: { [key in string]?: Array<LayoutAction> }, };
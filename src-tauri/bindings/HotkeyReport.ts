@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HotkeyConflict } from "./HotkeyConflict";
+
+/**
+ * The outcome of registering the stored hotkeys
+ */
+export type HotkeyReport = { 
+/**
+ * Shortcuts that were registered
+ */
+active: Array<string>, 
+/**
+ * Stored bindings that are not active
+ */
+inactive: Array<HotkeyConflict>, };
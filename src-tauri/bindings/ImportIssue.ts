@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Something an importer could not carry over as-is
+ */
+export type ImportIssue = { 
+/**
+ * The imported entry the issue refers to, e.g. a layout name or shortcut
+ */
+entry: string, message: string, 
+/**
+ * Whether the entry was dropped entirely rather than imported with changes
+ */
+skipped: boolean, };
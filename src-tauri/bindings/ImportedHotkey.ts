@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ImportedHotkey = { shortcut: string, 
+/**
+ * The action names in kebab-case, in the order they run
+ */
+actions: Array<string>, };
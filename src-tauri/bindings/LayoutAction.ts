@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LayoutAction = { "action": "left-half" } | { "action": "right-half" } | { "action": "center-half" } | { "action": "top-half" } | { "action": "bottom-half" } | { "action": "top-left" } | { "action": "top-right" } | { "action": "bottom-left" } | { "action": "bottom-right" } | { "action": "first-third" } | { "action": "center-third" } | { "action": "last-third" } | { "action": "first-two-thirds" } | { "action": "last-two-thirds" } | { "action": "maximize" } | { "action": "almost-maximize" } | { "action": "maximize-height" } | { "action": "smaller" } | { "action": "larger" } | { "action": "center" } | { "action": "center-prominently" } | { "action": "restore" } | { "action": "next-display" } | { "action": "previous-display" } | { "action": "move-left" } | { "action": "move-right" } | { "action": "move-up" } | { "action": "move-down" } | { "action": "first-fourth" } | { "action": "second-fourth" } | { "action": "third-fourth" } | { "action": "last-fourth" } | { "action": "first-three-fourths" } | { "action": "last-three-fourths" } | { "action": "top-left-sixth" } | { "action": "top-center-sixth" } | { "action": "top-right-sixth" } | { "action": "bottom-left-sixth" } | { "action": "bottom-center-sixth" } | { "action": "bottom-right-sixth" } | { "action": "top-left-third" } | { "action": "top-right-third" } | { "action": "bottom-left-third" } | { "action": "bottom-right-third" } | { "action": "apply-zone", zone_number: number, } | { "action": "activate-layout", layout_id: string, } | { "action": "activate-hotkey-profile", profile_id: string, } | { "action": "move-by", dx: number, dy: number, } | { "action": "resize-by", width: number, height: number, } | { "action": "window-mode" };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LayoutAction } from "./LayoutAction";
+
+/**
+ * What a rule does with a window that matches it
+ */
+export type RuleAction = { "type": "zone", layout_id: string, zone_number: number, } | { "type": "action", action: LayoutAction, } | { "type": "display", index: number, } | { "type": "ignore" };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single setting that changed, sent with the `settings-changed` event
+ */
+export type SettingChange = { key: string, old_value: unknown, new_value: unknown, };
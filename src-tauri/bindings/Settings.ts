@@ -8,4 +8,40 @@ show_zone_drag_overlay: boolean,
 /**
  * Opacity of the zone overlay during drag (0.0–1.0). Default 0.25 when unset.
  */
-zone_overlay_opacity: number, };
+zone_overlay_opacity: number, 
+/**
+ * Shortcut that starts a chord, e.g. "Control+Alt+Space". Chords are off when unset.
+ */
+leader_key: string | null, 
+/**
+ * How long to wait for the next key of a chord, in milliseconds
+ */
+chord_timeout_ms: number, 
+/**
+ * Show the keys that can follow while a chord is in progress
+ */
+show_chord_hints: boolean, 
+/**
+ * Holding a hotkey previews where the window goes, releasing applies it and Esc cancels
+ */
+hold_to_preview: boolean, 
+/**
+ * Repeat move and resize hotkeys while they are held
+ */
+key_repeat: boolean, 
+/**
+ * How long a hotkey is held before it starts repeating, in milliseconds
+ */
+key_repeat_delay_ms: number, 
+/**
+ * Time between the first repeats, in milliseconds. Repeats speed up from there.
+ */
+key_repeat_interval_ms: number, 
+/**
+ * The shortest time between repeats, in milliseconds
+ */
+key_repeat_min_interval_ms: number, 
+/**
+ * Leave hotkeys to the focused application while it is fullscreen, e.g. a game
+ */
+suppress_hotkeys_in_fullscreen: boolean, };
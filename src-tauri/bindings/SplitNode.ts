@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SplitDirection } from "./SplitDirection";
+
+/**
+ * A node in a split-tree layout. Leaves become zones, splits divide their area between
+ * their children according to `ratios`, which always sum to 1 so the layout stays gap-free.
+ */
+export type SplitNode = { "type": "zone", id: string, } | { "type": "split", id: string, direction: SplitDirection, ratios: Array<number>, children: Array<SplitNode>, };
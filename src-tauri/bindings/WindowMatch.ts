@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which windows a rule or an exclusion applies to. Every criterion that is set has to
+ * match.
+ */
+export type WindowMatch = { 
+/**
+ * Executable name on Windows, e.g. `Code.exe`, or bundle id on macOS, e.g.
+ * `com.microsoft.VSCode`
+ */
+app: string | null, 
+/**
+ * Regular expression searched for in the window title
+ */
+title: string | null, 
+/**
+ * Window class on Windows, e.g. `Chrome_WidgetWin_1`, or subrole on macOS, e.g.
+ * `AXStandardWindow`
+ */
+class: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which window a hotkey acts on
+ */
+export type WindowTarget = "focused" | "under-cursor" | "cursor-display";
@@ -1,4 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SplitNode } from "./SplitNode";
 import type { Zone } from "./Zone";
 
-export type ZoneLayout = { id: string, name: string, zones: Array<Zone>, screenWidth: number | null, screenHeight: number | null, };
+export type ZoneLayout = { id: string, name: string, zones: Array<Zone>, screenWidth: number | null, screenHeight: number | null, 
+/**
+ * Optional split-tree representation. When present, `zones` is compiled from it.
+ */
+splitTree?: SplitNode, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueSeverity } from "./IssueSeverity";
+
+export type ZoneLayoutIssue = { severity: IssueSeverity, message: string, } & ({ "type": "invalid-size", zone_id: string, } | { "type": "out-of-bounds", zone_id: string, } | { "type": "duplicate-id", zone_id: string, } | { "type": "duplicate-number", number: number, zone_ids: Array<string>, } | { "type": "overlap", zone_ids: Array<string>, area: number, } | { "type": "gap", uncovered: number, } | { "type": "missing-numbers", numbers: Array<number>, } | { "type": "unreachable-zone", zone_id: string, });
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Parametric layout templates. All zones are in percentages (0–100), numbered from 1.
+ */
+export type ZoneLayoutTemplate = { "type": "columns", count: number, } | { "type": "rows", count: number, } | { "type": "grid", columns: number, rows: number, } | { "type": "priority-grid", count: number, } | { "type": "focus" } | { "type": "three-column", center_ratio: number, };
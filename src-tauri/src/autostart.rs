@@ -1,8 +1,9 @@
 use serde_json::Value;
+use tauri::Listener;
 use tauri_plugin_autostart::MacosLauncher;
 use tauri_plugin_autostart::ManagerExt;
 
-use crate::store::settings::SettingsStore;
+use crate::store::settings::{SettingChange, SettingsStore, SETTINGS_CHANGED_EVENT};
 
 pub fn setup_autostart(app_handle: &tauri::AppHandle) {
     let _ = app_handle.plugin(tauri_plugin_autostart::init(
@@ -21,4 +22,21 @@ pub fn setup_autostart(app_handle: &tauri::AppHandle) {
             let _ = autostart_manager.disable();
         }
     }
+
+    // Follow the auto_start setting when it is changed later on
+    let app = app_handle.clone();
+    app_handle.listen(SETTINGS_CHANGED_EVENT, move |event| {
+        let Ok(changes) = serde_json::from_str::<Vec<SettingChange>>(event.payload()) else {
+            return;
+        };
+        for change in changes.iter().filter(|change| change.key == "auto_start") {
+            let result = match change.new_value {
+                Value::Bool(true) => app.autolaunch().enable(),
+                _ => app.autolaunch().disable(),
+            };
+            if let Err(e) = result {
+                eprintln!("Failed to update autostart: {}", e);
+            }
+        }
+    });
 }
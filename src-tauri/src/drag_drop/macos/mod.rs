@@ -6,13 +6,13 @@ use tauri::ipc::{Channel, InvokeResponseBody};
 use tauri_plugin_user_input::{EventType, InputEvent, InputEventData, UserInputExt};
 use monio::Key;
 use accessibility::AXUIElement;
-use crate::store::settings::SettingsStore;
-use crate::store::zone_layouts;
+use crate::store::cache;
 use crate::snapping::action::LayoutAction;
 use crate::snapping::macos::snap_window_with_element;
 use crate::drag_drop::overlay::ZoneOverlay;
 use crate::window::PRIMARY_WINDOW_NAME;
 use crate::window::macos::{get_frontmost_window, get_screen_dimensions_for_window, raise_window};
+use crate::window_rules::macos::is_excluded_window;
 
 static APP_HANDLE: Mutex<Option<AppHandle>> = Mutex::new(None);
 static DRAGGING: Mutex<bool> = Mutex::new(false);
@@ -236,6 +236,16 @@ fn handle_left_button_down() {
         Err(_) => return,
     };
     
+    // Leave drags on our own windows, the Dock and excluded apps alone
+    let excluded = APP_HANDLE
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|app_handle| is_excluded_window(app_handle, &window));
+    if excluded {
+        return;
+    }
+    
     // Start tracking the drag
     let mut dragging = DRAGGING.lock().unwrap();
     *dragging = true;
@@ -350,19 +360,16 @@ fn handle_left_button_up() {
 
 fn check_and_update_modifier_state(app_handle: &AppHandle, _window: &AXUIElement) {
     // Get modifier key from settings
-    let settings_store = match SettingsStore::new(app_handle) {
-        Ok(s) => s,
-        Err(_) => return,
+    let Ok(settings) = cache::settings(app_handle) else {
+        return;
     };
-
-    let modifier_key = match settings_store.get_zone_drag_modifier_key() {
-        Ok(Some(key)) => key,
-        _ => return,
+    let Some(modifier_key) = settings.zone_drag_modifier_key.as_deref() else {
+        return;
     };
 
     // Check if the modifier key is currently pressed (fast operation)
     let modifier_state = MODIFIER_STATE.lock().unwrap();
-    let modifier_pressed = modifier_state.is_modifier_pressed(&modifier_key);
+    let modifier_pressed = modifier_state.is_modifier_pressed(modifier_key);
     drop(modifier_state);
 
     // Update the flag if modifier is pressed (fast operation)
@@ -405,26 +412,18 @@ fn check_and_update_modifier_state(app_handle: &AppHandle, _window: &AXUIElement
         }
 
         // Respect setting: don't show overlay if user disabled visual indicators
-        let settings_store = match SettingsStore::new(&app_handle_clone) {
-            Ok(s) => s,
-            Err(_) => return,
-        };
-        if match settings_store.get_show_zone_drag_overlay() {
-            Ok(show) => !show,
-            _ => false,
-        } {
-            return;
+        if let Ok(settings) = cache::settings(&app_handle_clone) {
+            if !settings.show_zone_drag_overlay {
+                return;
+            }
         }
 
         // Get active zone layout, or return if not found
-        let active_layout_id = match zone_layouts::get_active_zone_layout_id(app_handle_clone.clone()) {
-          Ok(Some(id)) => id,
-          _ => return,
-        };
-
-        // Get zone layout, or return if not found
-        let layout = match zone_layouts::get_zone_layout(app_handle_clone.clone(), active_layout_id) {
-          Ok(Some(l)) => l,
+        let layout = match cache::active_layout(&app_handle_clone) {
+          Ok(layout) => match layout.as_ref() {
+              Some(l) => l.clone(),
+              None => return,
+          },
           _ => return,
         };
 
@@ -442,14 +441,10 @@ fn check_and_update_modifier_state(app_handle: &AppHandle, _window: &AXUIElement
 
 fn handle_drop(app_handle: &AppHandle, window: &AXUIElement, x: i32, y: i32) -> Result<(), String> {
     // Get active zone layout
-    let active_layout_id = match zone_layouts::get_active_zone_layout_id(app_handle.clone()) {
-        Ok(Some(id)) => id,
-        _ => return Err("No active zone layout".to_string()),
-    };
-
-    let layout = match zone_layouts::get_zone_layout(app_handle.clone(), active_layout_id) {
-        Ok(Some(l)) => l,
-        _ => return Err("Failed to get zone layout".to_string()),
+    let layout = cache::active_layout(app_handle)?;
+    let layout = match layout.as_ref() {
+        Some(l) => l,
+        None => return Err("No active zone layout".to_string()),
     };
 
     // Get screen dimensions
@@ -459,7 +454,7 @@ fn handle_drop(app_handle: &AppHandle, window: &AXUIElement, x: i32, y: i32) ->
     let zone = layout.get_zone_at_position(x, y, screen);
     if let Some(zone) = zone {
         // Snap window to zone using the specific window element we tracked during drag
-        let action = LayoutAction::ApplyZone(zone.number);
+        let action = LayoutAction::ApplyZone { zone_number: zone.number };
         snap_window_with_element(action, Some(app_handle.clone()), window)?;
     }
 
@@ -1,4 +1,4 @@
-use crate::store::settings::SettingsStore;
+use crate::store::cache;
 use crate::store::zone_layouts::ZoneLayout;
 use crate::snapping::common::ScreenDimensions;
 use display_info::DisplayInfo;
@@ -24,6 +24,17 @@ impl ZoneOverlay {
         app_handle: &tauri::AppHandle,
         layout: &ZoneLayout,
         screen: ScreenDimensions,
+    ) -> Result<(), String> {
+        self.show_highlighted(app_handle, layout, screen, None)
+    }
+
+    /// Show the overlay with one zone highlighted until the cursor hovers another
+    pub fn show_highlighted(
+        &self,
+        app_handle: &tauri::AppHandle,
+        layout: &ZoneLayout,
+        screen: ScreenDimensions,
+        highlighted_zone_id: Option<&str>,
     ) -> Result<(), String> {
         // Hide existing overlay if any
         self.hide()?;
@@ -40,9 +51,8 @@ impl ZoneOverlay {
         let window_label = format!("zone-overlay-{}", screen_idx);
 
         // Zone overlay opacity from settings (0.0–1.0), default 0.25
-        let opacity = SettingsStore::new(app_handle)
-            .ok()
-            .and_then(|s| s.get_zone_overlay_opacity().ok())
+        let opacity = cache::settings(app_handle)
+            .map(|s| s.zone_overlay_opacity)
             .unwrap_or(0.25);
 
         // Prepare overlay data for URL params and events
@@ -55,6 +65,7 @@ impl ZoneOverlay {
                 "height": screen.height,
             },
             "opacity": opacity,
+            "highlightedZoneId": highlighted_zone_id,
         });
 
         let mut windows = OVERLAY_WINDOWS.lock().unwrap();
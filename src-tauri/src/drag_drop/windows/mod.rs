@@ -19,13 +19,13 @@ use windows::{
         },
     },
 };
-use crate::store::settings::SettingsStore;
-use crate::store::zone_layouts;
+use crate::store::cache;
 use crate::snapping::common::ScreenDimensions;
 use crate::snapping::action::LayoutAction;
 use crate::snapping::windows::snap_window_with_handle;
 use crate::drag_drop::overlay::ZoneOverlay;
 use crate::window::windows::get_screen_dimensions_for_window;
+use crate::window_rules::windows::is_excluded_window;
 
 static APP_HANDLE: Mutex<Option<AppHandle>> = Mutex::new(None);
 static DRAGGING: Mutex<bool> = Mutex::new(false);
@@ -185,6 +185,11 @@ fn handle_left_button_down(app_handle: &AppHandle, x: i32, y: i32) {
         return;
     }
     
+    // Leave drags on our own windows, the desktop and excluded apps alone
+    if is_excluded_window(app_handle, hwnd) {
+        return;
+    }
+    
     // Start tracking the drag
     let mut dragging = DRAGGING.lock().unwrap();
     *dragging = true;
@@ -340,18 +345,15 @@ fn handle_left_button_up(app_handle: &AppHandle, x: i32, y: i32) {
 
 fn check_and_update_modifier_state(app_handle: &AppHandle, hwnd: HWND) {
     // Fast path: Check modifier key state quickly
-    let settings_store = match SettingsStore::new(app_handle) {
-        Ok(s) => s,
-        Err(_) => return,
+    let Ok(settings) = cache::settings(app_handle) else {
+        return;
     };
-
-    let modifier_key = match settings_store.get_zone_drag_modifier_key() {
-        Ok(Some(key)) => key,
-        _ => return,
+    let Some(modifier_key) = settings.zone_drag_modifier_key.as_deref() else {
+        return;
     };
 
     // Check if the modifier key is currently pressed (fast operation)
-    let modifier_pressed = match modifier_key.as_str() {
+    let modifier_pressed = match modifier_key {
         "control" => unsafe { GetAsyncKeyState(0x11) & 0x8000u16 as i16 != 0 }, // VK_CONTROL
         "alt" => unsafe { GetAsyncKeyState(0x12) & 0x8000u16 as i16 != 0 },     // VK_MENU
         "shift" => unsafe { GetAsyncKeyState(0x10) & 0x8000u16 as i16 != 0 },    // VK_SHIFT
@@ -399,26 +401,18 @@ fn check_and_update_modifier_state(app_handle: &AppHandle, hwnd: HWND) {
         }
 
         // Respect setting: don't show overlay if user disabled visual indicators
-        let settings_store = match SettingsStore::new(&app_handle_clone) {
-            Ok(s) => s,
-            Err(_) => return,
-        };
-        if match settings_store.get_show_zone_drag_overlay() {
-            Ok(show) => !show,
-            _ => false,
-        } {
-            return;
+        if let Ok(settings) = cache::settings(&app_handle_clone) {
+            if !settings.show_zone_drag_overlay {
+                return;
+            }
         }
 
         // Get active zone layout, or return if not found
-        let active_layout_id = match zone_layouts::get_active_zone_layout_id(app_handle_clone.clone()) {
-            Ok(Some(id)) => id,
-            _ => return,
-        };
-
-        // Get zone layout, or return if not found
-        let layout = match zone_layouts::get_zone_layout(app_handle_clone.clone(), active_layout_id) {
-            Ok(Some(l)) => l,
+        let layout = match cache::active_layout(&app_handle_clone) {
+            Ok(layout) => match layout.as_ref() {
+                Some(l) => l.clone(),
+                None => return,
+            },
             _ => return,
         };
 
@@ -436,14 +430,10 @@ fn check_and_update_modifier_state(app_handle: &AppHandle, hwnd: HWND) {
 
 fn handle_drop(app_handle: &AppHandle, hwnd: HWND, x: i32, y: i32) -> Result<(), String> {
     // Get active zone layout
-    let active_layout_id = match zone_layouts::get_active_zone_layout_id(app_handle.clone()) {
-        Ok(Some(id)) => id,
-        _ => return Err("No active zone layout".to_string()),
-    };
-
-    let layout = match zone_layouts::get_zone_layout(app_handle.clone(), active_layout_id) {
-        Ok(Some(l)) => l,
-        _ => return Err("Failed to get zone layout".to_string()),
+    let layout = cache::active_layout(app_handle)?;
+    let layout = match layout.as_ref() {
+        Some(l) => l,
+        None => return Err("No active zone layout".to_string()),
     };
 
     // Get screen dimensions
@@ -453,7 +443,7 @@ fn handle_drop(app_handle: &AppHandle, hwnd: HWND, x: i32, y: i32) -> Result<(),
     let zone = layout.get_zone_at_position(x, y, screen);
     if let Some(zone) = zone {
         // Snap window to zone using the specific window handle we tracked during drag
-        let action = LayoutAction::ApplyZone(zone.number);
+        let action = LayoutAction::ApplyZone { zone_number: zone.number };
         snap_window_with_handle(action, Some(app_handle.clone()), hwnd)?;
     }
 
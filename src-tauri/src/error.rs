@@ -0,0 +1,118 @@
+use serde::{Serialize, Serializer};
+use ts_rs::TS;
+
+use crate::store::settings::SettingsError;
+
+/// Errors returned by commands and the snapping code
+#[derive(Debug, thiserror::Error)]
+pub enum CrossZonesError {
+    /// A store could not be opened, read or saved
+    #[error("Store error: {0}")]
+    Store(String),
+    /// The OS refused or failed a window, screen or shortcut operation
+    #[error("{0}")]
+    Platform(String),
+    /// The app is missing an OS permission, such as macOS accessibility access
+    #[error("Permission denied: {0}")]
+    Permission(String),
+    /// Input from the frontend or a file was rejected
+    #[error("{0}")]
+    Validation(String),
+    /// A layout, shortcut, window or file does not exist
+    #[error("{0} not found")]
+    NotFound(String),
+    /// A shortcut is already taken, by another binding, the OS or another app
+    #[error("{0}")]
+    Conflict(String),
+}
+
+/// Stable error codes for the frontend. Existing codes must never change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum ErrorCode {
+    Store,
+    Platform,
+    Permission,
+    Validation,
+    NotFound,
+    Conflict,
+}
+
+/// How a `CrossZonesError` reaches the frontend
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, CrossZonesError>;
+
+impl CrossZonesError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            CrossZonesError::Store(_) => ErrorCode::Store,
+            CrossZonesError::Platform(_) => ErrorCode::Platform,
+            CrossZonesError::Permission(_) => ErrorCode::Permission,
+            CrossZonesError::Validation(_) => ErrorCode::Validation,
+            CrossZonesError::NotFound(_) => ErrorCode::NotFound,
+            CrossZonesError::Conflict(_) => ErrorCode::Conflict,
+        }
+    }
+
+    /// Reading an import or config file failed
+    pub fn file(path: &str, error: std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => CrossZonesError::NotFound(format!("File {}", path)),
+            std::io::ErrorKind::PermissionDenied => {
+                CrossZonesError::Permission(format!("Cannot access {}: {}", path, error))
+            }
+            _ => CrossZonesError::Platform(format!("Failed to access {}: {}", path, error)),
+        }
+    }
+}
+
+impl Serialize for CrossZonesError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        CommandError {
+            code: self.code(),
+            message: self.to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl From<tauri_plugin_store::Error> for CrossZonesError {
+    fn from(error: tauri_plugin_store::Error) -> Self {
+        CrossZonesError::Store(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CrossZonesError {
+    fn from(error: serde_json::Error) -> Self {
+        CrossZonesError::Store(format!("Failed to (de)serialize: {}", error))
+    }
+}
+
+impl From<SettingsError> for CrossZonesError {
+    fn from(error: SettingsError) -> Self {
+        match error {
+            SettingsError::Invalid(message) => CrossZonesError::Validation(message),
+            error => CrossZonesError::Store(error.to_string()),
+        }
+    }
+}
+
+impl From<tauri_plugin_global_shortcut::Error> for CrossZonesError {
+    fn from(error: tauri_plugin_global_shortcut::Error) -> Self {
+        CrossZonesError::Platform(error.to_string())
+    }
+}
+
+/// Lets code that still reports plain strings use `?` on these errors
+impl From<CrossZonesError> for String {
+    fn from(error: CrossZonesError) -> Self {
+        error.to_string()
+    }
+}
@@ -0,0 +1,487 @@
+use base64::{engine::general_purpose::URL_SAFE, Engine as _};
+use display_info::DisplayInfo;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+use tauri::{Emitter, Listener, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder};
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Shortcut};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::store::cache;
+use crate::store::chords::{chord_bindings, sequence_key, ChordBinding, CHORDS_STORE_NAME};
+use crate::store::hotkey_options::WindowTarget;
+use crate::store::hotkeys::read_actions;
+use crate::store::settings::{SettingChange, Settings, SETTINGS_CHANGED_EVENT};
+
+/// Emitted to the hint window with a `ChordHint` whenever the pressed keys change
+pub const CHORD_HINT_EVENT: &str = "chord-hint";
+
+const HINT_WINDOW_LABEL: &str = "chord-hint";
+const HINT_WIDTH: f64 = 320.0;
+const HINT_HEIGHT: f64 = 360.0;
+
+/// The leader and the keys registered while a chord is in progress, so the shortcut
+/// handler knows what to pass on to the chord thread
+static KEYS: Mutex<Vec<Shortcut>> = Mutex::new(Vec::new());
+
+static EVENTS: OnceLock<Sender<ChordEvent>> = OnceLock::new();
+
+enum ChordEvent {
+    Key(Shortcut),
+    LeaderChanged,
+}
+
+/// A key that can be pressed next, shown in the hint window
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ChordContinuation {
+    pub key: String,
+    /// The key as printed on the keyboard, e.g. `H` for `KeyH`
+    pub label: String,
+    /// Names of the actions this key runs, empty if more keys have to follow
+    pub actions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ChordHint {
+    /// Labels of the keys pressed since the leader
+    pub pressed: Vec<String>,
+    pub continuations: Vec<ChordContinuation>,
+}
+
+/// What the keys pressed since the leader lead to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChordMatch {
+    /// No chord starts with these keys
+    None,
+    /// A chord is complete and no longer chord starts with it
+    Complete(Vec<LayoutAction>),
+    /// More keys can follow. `complete` runs if none is pressed before the timeout.
+    Partial {
+        next: Vec<String>,
+        complete: Option<Vec<LayoutAction>>,
+    },
+}
+
+pub fn match_chord(chords: &[ChordBinding], pressed: &[String]) -> ChordMatch {
+    let mut next = BTreeSet::new();
+    let mut complete = None;
+
+    for chord in chords
+        .iter()
+        .filter(|chord| chord.sequence.starts_with(pressed))
+    {
+        match chord.sequence.get(pressed.len()) {
+            Some(key) => {
+                next.insert(key.clone());
+            }
+            None => complete = Some(chord.actions.clone()),
+        }
+    }
+
+    if !next.is_empty() {
+        return ChordMatch::Partial {
+            next: next.into_iter().collect(),
+            complete,
+        };
+    }
+    match complete {
+        Some(actions) => ChordMatch::Complete(actions),
+        None => ChordMatch::None,
+    }
+}
+
+fn key_label(key: &str) -> String {
+    key.strip_prefix("Key")
+        .or_else(|| key.strip_prefix("Digit"))
+        .unwrap_or(key)
+        .to_string()
+}
+
+fn hint(chords: &[ChordBinding], pressed: &[String], next: &[String]) -> ChordHint {
+    let continuations = next
+        .iter()
+        .map(|key| {
+            let actions = chords
+                .iter()
+                .find(|chord| {
+                    chord.sequence.len() == pressed.len() + 1
+                        && chord.sequence.starts_with(pressed)
+                        && chord.sequence.last() == Some(key)
+                })
+                .map(|chord| chord.actions.iter().map(|a| a.name().to_string()).collect())
+                .unwrap_or_default();
+            ChordContinuation {
+                key: key.clone(),
+                label: key_label(key),
+                actions,
+            }
+        })
+        .collect();
+
+    ChordHint {
+        pressed: pressed.iter().map(|key| key_label(key)).collect(),
+        continuations,
+    }
+}
+
+/// Chord state, owned by the chord thread. Shortcuts are only registered from here, never
+/// from the shortcut handler, which runs with the shortcut plugin's lock held.
+struct ChordSession {
+    app: tauri::AppHandle,
+    leader: Option<Shortcut>,
+    /// Keys pressed since the leader, `None` while no chord is in progress
+    pressed: Option<Vec<String>>,
+    /// Actions to run if the timeout passes before the next key
+    pending: Option<Vec<LayoutAction>>,
+    /// Keys registered for the current step of the chord
+    registered: Vec<Shortcut>,
+}
+
+impl ChordSession {
+    fn new(app: tauri::AppHandle) -> Self {
+        Self {
+            app,
+            leader: None,
+            pressed: None,
+            pending: None,
+            registered: Vec::new(),
+        }
+    }
+
+    fn settings(&self) -> Settings {
+        cache::settings(&self.app)
+            .map(|settings| (*settings).clone())
+            .unwrap_or_default()
+    }
+
+    /// Register the leader key from the settings in place of the previous one
+    fn update_leader(&mut self) {
+        self.end();
+
+        let manager = self.app.global_shortcut();
+        if let Some(leader) = self.leader.take() {
+            let _ = manager.unregister(leader);
+        }
+
+        if let Some(key) = self.settings().leader_key {
+            match Shortcut::from_str(&key) {
+                Ok(leader) if manager.is_registered(leader) => {
+                    eprintln!("Leader key {} is already bound to a hotkey", key);
+                }
+                Ok(leader) => match manager.register(leader) {
+                    Ok(()) => self.leader = Some(leader),
+                    Err(e) => eprintln!("Failed to register leader key {}: {}", key, e),
+                },
+                Err(e) => eprintln!("Invalid leader key {}: {}", key, e),
+            }
+        }
+
+        self.publish_keys();
+    }
+
+    fn press(&mut self, key: Shortcut) {
+        if Some(key) == self.leader {
+            // The leader cancels a chord in progress
+            if self.pressed.is_some() {
+                self.end();
+            } else {
+                self.pressed = Some(Vec::new());
+                self.advance();
+            }
+            return;
+        }
+
+        let Some(pressed) = &mut self.pressed else {
+            return;
+        };
+        if key == Shortcut::new(None, Code::Escape) {
+            self.end();
+            return;
+        }
+        pressed.push(key.to_string());
+        self.advance();
+    }
+
+    fn advance(&mut self) {
+        let pressed = self.pressed.clone().unwrap_or_default();
+        let chords = match chord_bindings(&self.app) {
+            Ok(chords) => chords,
+            Err(e) => {
+                eprintln!("Failed to load chords: {}", e);
+                self.end();
+                return;
+            }
+        };
+
+        match match_chord(&chords, &pressed) {
+            ChordMatch::None => self.end(),
+            ChordMatch::Complete(actions) => {
+                self.end();
+                self.run(&actions);
+            }
+            ChordMatch::Partial { next, complete } => {
+                self.pending = complete;
+                self.register_keys(&next);
+                if self.settings().show_chord_hints {
+                    if let Err(e) = show_hint(&self.app, &hint(&chords, &pressed, &next)) {
+                        eprintln!("Failed to show chord hint: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// No key followed in time, so run the chord completed so far, if any
+    fn expire(&mut self) {
+        let pending = self.pending.take();
+        self.end();
+        if let Some(actions) = pending {
+            self.run(&actions);
+        }
+    }
+
+    fn end(&mut self) {
+        self.pressed = None;
+        self.pending = None;
+        self.register_keys(&[]);
+        hide_hint(&self.app);
+    }
+
+    fn run(&self, actions: &[LayoutAction]) {
+        for action in actions {
+            if let Err(e) = super::run_action(&self.app, action, WindowTarget::Focused) {
+                eprintln!("Chord action {} failed: {}", action.name(), e);
+                return;
+            }
+        }
+    }
+
+    /// Register `keys` and Escape for the next step, releasing the previous step's keys.
+    /// Keys that are bound to a hotkey keep working as that hotkey.
+    fn register_keys(&mut self, keys: &[String]) {
+        let manager = self.app.global_shortcut();
+        for key in self.registered.drain(..) {
+            let _ = manager.unregister(key);
+        }
+
+        if !keys.is_empty() {
+            let shortcuts = keys
+                .iter()
+                .filter_map(|key| Shortcut::from_str(key).ok())
+                .chain([Shortcut::new(None, Code::Escape)]);
+            for shortcut in shortcuts {
+                if manager.is_registered(shortcut) {
+                    continue;
+                }
+                match manager.register(shortcut) {
+                    Ok(()) => self.registered.push(shortcut),
+                    Err(e) => eprintln!("Failed to register chord key {}: {}", shortcut, e),
+                }
+            }
+        }
+
+        self.publish_keys();
+    }
+
+    fn publish_keys(&self) {
+        *KEYS.lock().unwrap() = self
+            .leader
+            .iter()
+            .chain(&self.registered)
+            .copied()
+            .collect();
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_millis(self.settings().chord_timeout_ms as u64)
+    }
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<ChordEvent>) {
+    let mut session = ChordSession::new(app);
+    session.update_leader();
+
+    loop {
+        let event = if session.pressed.is_some() {
+            events.recv_timeout(session.timeout())
+        } else {
+            events.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+
+        match event {
+            Ok(ChordEvent::Key(key)) => session.press(key),
+            Ok(ChordEvent::LeaderChanged) => session.update_leader(),
+            Err(RecvTimeoutError::Timeout) => session.expire(),
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+fn hint_window(app: &tauri::AppHandle, hint: &ChordHint) -> Result<WebviewWindow> {
+    if let Some(window) = app.get_webview_window(HINT_WINDOW_LABEL) {
+        return Ok(window);
+    }
+
+    // Bottom center of the primary screen
+    let screens = DisplayInfo::all()
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to get screens: {}", e)))?;
+    let screen = screens
+        .iter()
+        .find(|screen| screen.is_primary)
+        .or(screens.first())
+        .ok_or_else(|| CrossZonesError::NotFound("Screen".to_string()))?;
+    let x = screen.x as f64 + (screen.width as f64 - HINT_WIDTH) / 2.0;
+    let y = screen.y as f64 + screen.height as f64 - HINT_HEIGHT - 80.0;
+
+    // The first hint goes in the URL, since the page isn't listening yet
+    let data = URL_SAFE.encode(serde_json::to_string(hint)?.as_bytes());
+    let url = format!("index.html?data={}#/chord-hint", data);
+
+    let mut window_builder =
+        WebviewWindowBuilder::new(app, HINT_WINDOW_LABEL, WebviewUrl::App(url.into()))
+            .title("Chord Hint")
+            .inner_size(HINT_WIDTH, HINT_HEIGHT)
+            .position(x, y)
+            .visible(false)
+            .resizable(false)
+            .decorations(false)
+            .skip_taskbar(true)
+            .closable(false)
+            .focused(false)
+            .always_on_top(true);
+
+    if app.config().app.macos_private_api || cfg!(target_os = "windows") {
+        window_builder = window_builder.transparent(true);
+    }
+
+    window_builder
+        .build()
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to create hint window: {}", e)))
+}
+
+fn show_hint(app: &tauri::AppHandle, hint: &ChordHint) -> Result<()> {
+    let window = hint_window(app, hint)?;
+    let _ = window.emit(CHORD_HINT_EVENT, hint);
+    window
+        .show()
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to show hint window: {}", e)))
+}
+
+fn hide_hint(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window(HINT_WINDOW_LABEL) {
+        let _ = window.hide();
+    }
+}
+
+/// Whether `shortcut` is the leader key or a key of the chord in progress
+pub fn is_chord_key(shortcut: &Shortcut) -> bool {
+    KEYS.lock().unwrap().contains(shortcut)
+}
+
+/// Pass the leader and chord keys on to the chord thread. Returns whether `shortcut`
+/// was one of them.
+pub fn handle_key(shortcut: &Shortcut) -> bool {
+    if !is_chord_key(shortcut) {
+        return false;
+    }
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(ChordEvent::Key(*shortcut));
+    }
+    true
+}
+
+/// Start the chord thread and follow changes to the leader key
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender.clone()).is_err() {
+        return;
+    }
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+
+    app_handle.listen(SETTINGS_CHANGED_EVENT, move |event| {
+        let Ok(changes) = serde_json::from_str::<Vec<SettingChange>>(event.payload()) else {
+            return;
+        };
+        if changes.iter().any(|change| change.key == "leader_key") {
+            let _ = sender.send(ChordEvent::LeaderChanged);
+        }
+    });
+}
+
+/// Check a chord key and bring it into the form `Shortcut` prints, e.g. `h` → `KeyH`
+fn parse_chord_key(key: &str) -> Result<String> {
+    let shortcut = Shortcut::from_str(key)
+        .map_err(|e| CrossZonesError::Validation(format!("Invalid chord key {}: {}", key, e)))?;
+    if !shortcut.mods.is_empty() {
+        return Err(CrossZonesError::Validation(format!(
+            "Chord keys can't have modifiers, got {}",
+            key
+        )));
+    }
+    Ok(shortcut.to_string())
+}
+
+fn parse_sequence(sequence: &[String]) -> Result<Vec<String>> {
+    if sequence.is_empty() {
+        return Err(CrossZonesError::Validation(
+            "A chord needs at least one key".to_string(),
+        ));
+    }
+    sequence.iter().map(|key| parse_chord_key(key)).collect()
+}
+
+#[tauri::command]
+pub fn get_all_chords(app: tauri::AppHandle) -> Result<Vec<ChordBinding>> {
+    chord_bindings(&app)
+}
+
+/// Bind keys pressed one after the other following the leader key to actions
+#[tauri::command]
+pub fn register_chord(
+    app: tauri::AppHandle,
+    sequence: Vec<String>,
+    actions: Vec<LayoutAction>,
+) -> Result<()> {
+    let key = sequence_key(&parse_sequence(&sequence)?);
+    if actions.is_empty() {
+        return Err(CrossZonesError::Validation(format!(
+            "Chord {} needs at least one action",
+            key
+        )));
+    }
+
+    let store = app.store(CHORDS_STORE_NAME)?;
+    if let Some(existing) = store.get(&key) {
+        if read_actions(&existing).as_ref() != Ok(&actions) {
+            return Err(CrossZonesError::Conflict(format!(
+                "Chord {} is already bound",
+                key
+            )));
+        }
+    }
+
+    store.set(key, serde_json::to_value(&actions)?);
+    store.save()?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn unregister_chord(app: tauri::AppHandle, sequence: Vec<String>) -> Result<()> {
+    let key = sequence_key(&parse_sequence(&sequence)?);
+
+    let store = app.store(CHORDS_STORE_NAME)?;
+    store.delete(key);
+    store.save()?;
+    Ok(())
+}
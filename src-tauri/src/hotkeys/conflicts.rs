@@ -0,0 +1,195 @@
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::str::FromStr;
+use tauri_plugin_global_shortcut::Shortcut;
+use ts_rs::TS;
+
+use crate::error::CrossZonesError;
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkeys::read_actions;
+
+/// Combinations the OS handles itself. Registering them fails, never fires or takes
+/// the combination away from the system.
+#[cfg(target_os = "windows")]
+const RESERVED_SHORTCUTS: &[&str] = &[
+    "Alt+Tab",
+    "Alt+F4",
+    "Control+Alt+Delete",
+    "Control+Shift+Escape",
+    "Super+D",
+    "Super+E",
+    "Super+L",
+    "Super+R",
+    "Super+Tab",
+];
+
+#[cfg(target_os = "macos")]
+const RESERVED_SHORTCUTS: &[&str] = &[
+    "Super+Tab",
+    "Super+Space",
+    "Super+Alt+Escape",
+    "Super+Shift+3",
+    "Super+Shift+4",
+    "Super+Shift+5",
+    "Control+Super+Q",
+    "Control+ArrowUp",
+    "Control+ArrowDown",
+];
+
+#[cfg(target_os = "linux")]
+const RESERVED_SHORTCUTS: &[&str] = &["Alt+Tab", "Alt+F4", "Control+Alt+Delete", "Super+L"];
+
+/// Why a stored hotkey is not active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum ConflictKind {
+    /// The shortcut or its action can't be read
+    Invalid,
+    /// Another stored entry uses the same key combination
+    Duplicate,
+    /// The OS keeps the combination for itself
+    Reserved,
+    /// Registering failed, usually because another app owns the shortcut
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct HotkeyConflict {
+    pub shortcut: String,
+    pub kind: ConflictKind,
+    pub message: String,
+}
+
+/// The outcome of registering the stored hotkeys
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct HotkeyReport {
+    /// Shortcuts that were registered
+    pub active: Vec<String>,
+    /// Stored bindings that are not active
+    pub inactive: Vec<HotkeyConflict>,
+}
+
+impl HotkeyConflict {
+    pub fn new(
+        shortcut: impl Into<String>,
+        kind: ConflictKind,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            shortcut: shortcut.into(),
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<HotkeyConflict> for CrossZonesError {
+    fn from(conflict: HotkeyConflict) -> Self {
+        CrossZonesError::Conflict(conflict.message)
+    }
+}
+
+pub fn is_reserved(shortcut: &Shortcut) -> bool {
+    RESERVED_SHORTCUTS
+        .iter()
+        .filter_map(|reserved| Shortcut::from_str(reserved).ok())
+        .any(|reserved| reserved == *shortcut)
+}
+
+/// Action names for messages, e.g. `activate-layout, apply-zone`
+fn describe(actions: &[LayoutAction]) -> String {
+    actions
+        .iter()
+        .map(|action| action.name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Split stored shortcut → actions entries into bindings that can be registered and
+/// the ones that can't. Of several entries for the same keys, the first by name wins.
+pub fn check_bindings(
+    mut entries: Vec<(String, JsonValue)>,
+) -> (Vec<(Shortcut, Vec<LayoutAction>)>, Vec<HotkeyConflict>) {
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut bindings: Vec<(Shortcut, Vec<LayoutAction>)> = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for (key, value) in entries {
+        let shortcut = match Shortcut::from_str(&key) {
+            Ok(shortcut) => shortcut,
+            Err(e) => {
+                conflicts.push(HotkeyConflict::new(
+                    &key,
+                    ConflictKind::Invalid,
+                    format!("Invalid shortcut {}: {}", key, e),
+                ));
+                continue;
+            }
+        };
+        let actions = match read_actions(&value) {
+            Ok(actions) => actions,
+            Err(e) => {
+                conflicts.push(HotkeyConflict::new(&key, ConflictKind::Invalid, e));
+                continue;
+            }
+        };
+
+        if is_reserved(&shortcut) {
+            conflicts.push(HotkeyConflict::new(
+                &key,
+                ConflictKind::Reserved,
+                format!("{} is reserved by the system", key),
+            ));
+        } else if let Some((_, other)) = bindings.iter().find(|(s, _)| *s == shortcut) {
+            conflicts.push(HotkeyConflict::new(
+                &key,
+                ConflictKind::Duplicate,
+                format!(
+                    "{} uses the same keys as the {} hotkey",
+                    key,
+                    describe(other)
+                ),
+            ));
+        } else {
+            bindings.push((shortcut, actions));
+        }
+    }
+
+    (bindings, conflicts)
+}
+
+/// Why `shortcut` can't be bound to `actions`, given the stored entries.
+/// Rebinding a shortcut to the actions it already has is not a conflict.
+pub fn check_binding(
+    shortcut: &Shortcut,
+    actions: &[LayoutAction],
+    entries: &[(String, JsonValue)],
+) -> Option<HotkeyConflict> {
+    let key = shortcut.to_string();
+
+    if is_reserved(shortcut) {
+        return Some(HotkeyConflict::new(
+            &key,
+            ConflictKind::Reserved,
+            format!("{} is reserved by the system", key),
+        ));
+    }
+
+    entries
+        .iter()
+        .filter(|(stored, _)| Shortcut::from_str(stored).is_ok_and(|stored| stored == *shortcut))
+        .find_map(|(_, value)| match read_actions(value) {
+            Ok(existing) if existing == actions => None,
+            Ok(existing) => Some(format!(
+                "{} is already bound to {}",
+                key,
+                describe(&existing)
+            )),
+            Err(_) => Some(format!("{} is already bound", key)),
+        })
+        .map(|message| HotkeyConflict::new(&key, ConflictKind::Duplicate, message))
+}
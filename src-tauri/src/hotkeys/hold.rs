@@ -0,0 +1,229 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Shortcut};
+
+use crate::drag_drop::overlay::ZoneOverlay;
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::snapping::common::ScreenDimensions;
+use crate::snapping::preview_window;
+use crate::snapping::window_rect::WindowRect;
+use crate::store::cache;
+use crate::store::hotkey_options::WindowTarget;
+use crate::store::zone_layouts::{Zone, ZoneLayout};
+
+const PREVIEW_ZONE_ID: &str = "hotkey-preview";
+
+/// Escape while it is registered to cancel a held hotkey
+static KEYS: Mutex<Vec<Shortcut>> = Mutex::new(Vec::new());
+
+static EVENTS: OnceLock<Sender<HoldEvent>> = OnceLock::new();
+
+enum HoldEvent {
+    Press(Shortcut, LayoutAction),
+    Release(Shortcut),
+    Cancel,
+}
+
+/// Whether holding a hotkey bound to `action` can show where the window goes
+fn can_preview(action: &LayoutAction) -> bool {
+    !matches!(
+        action,
+        LayoutAction::ActivateLayout { .. }
+            | LayoutAction::ActivateHotkeyProfile { .. }
+            | LayoutAction::WindowMode
+    )
+}
+
+/// A layout with a single unnumbered zone covering `rect`, in percent of the screen
+fn preview_layout(rect: WindowRect, screen: ScreenDimensions) -> ZoneLayout {
+    let percent = |value: i32, size: i32| value as f64 / size as f64 * 100.0;
+
+    ZoneLayout {
+        id: PREVIEW_ZONE_ID.to_string(),
+        name: "Preview".to_string(),
+        zones: vec![Zone {
+            id: PREVIEW_ZONE_ID.to_string(),
+            x: percent(rect.x - screen.x, screen.width),
+            y: percent(rect.y - screen.y, screen.height),
+            width: percent(rect.width, screen.width),
+            height: percent(rect.height, screen.height),
+            number: 0,
+        }],
+        screen_width: None,
+        screen_height: None,
+        split_tree: None,
+    }
+}
+
+/// State of the held hotkey, owned by the hold thread. Like chords, Escape is only
+/// registered from here, never from the shortcut handler.
+struct HoldSession {
+    app: tauri::AppHandle,
+    overlay: ZoneOverlay,
+    held: Option<(Shortcut, LayoutAction)>,
+    escape: Option<Shortcut>,
+}
+
+impl HoldSession {
+    fn new(app: tauri::AppHandle) -> Self {
+        Self {
+            app,
+            overlay: ZoneOverlay::new(),
+            held: None,
+            escape: None,
+        }
+    }
+
+    /// Another hotkey pressed while one is held takes over the preview, and is the one
+    /// applied on its release
+    fn press(&mut self, shortcut: Shortcut, action: LayoutAction) {
+        // The held hotkey again, as the OS repeats it
+        if self
+            .held
+            .as_ref()
+            .is_some_and(|(held, _)| *held == shortcut)
+        {
+            return;
+        }
+
+        if let Err(e) = self.show_preview(&action) {
+            eprintln!("Failed to preview {}: {}", action.name(), e);
+        }
+        if self.held.replace((shortcut, action)).is_none() {
+            self.register_escape();
+        }
+    }
+
+    /// Zone actions show the whole active layout with the target zone highlighted,
+    /// other actions the rect the window would get
+    fn show_preview(&self, action: &LayoutAction) -> Result<()> {
+        let (rect, screen) = preview_window(action.clone(), Some(self.app.clone()))?;
+
+        if let LayoutAction::ApplyZone { zone_number } = action {
+            let active_layout = cache::active_layout(&self.app)?;
+            if let Some(layout) = active_layout.as_ref() {
+                if let Some(zone) = layout.zones.iter().find(|z| z.number == *zone_number) {
+                    return self
+                        .overlay
+                        .show_highlighted(&self.app, layout, screen, Some(&zone.id))
+                        .map_err(CrossZonesError::Platform);
+                }
+            }
+        }
+
+        self.overlay
+            .show_highlighted(
+                &self.app,
+                &preview_layout(rect, screen),
+                screen,
+                Some(PREVIEW_ZONE_ID),
+            )
+            .map_err(CrossZonesError::Platform)
+    }
+
+    fn release(&mut self, shortcut: Shortcut) {
+        let Some((held, action)) = self.held.take() else {
+            return;
+        };
+        if held != shortcut {
+            self.held = Some((held, action));
+            return;
+        }
+
+        self.end();
+        if let Err(e) = super::run_action(&self.app, &action, WindowTarget::Focused) {
+            eprintln!("Hotkey {} failed: {}", shortcut, e);
+        }
+    }
+
+    fn end(&mut self) {
+        self.held = None;
+        let _ = self.overlay.hide();
+
+        if let Some(escape) = self.escape.take() {
+            let _ = self.app.global_shortcut().unregister(escape);
+        }
+        KEYS.lock().unwrap().clear();
+    }
+
+    /// Register Escape to cancel, unless it is bound to a hotkey
+    fn register_escape(&mut self) {
+        let escape = Shortcut::new(None, Code::Escape);
+        let manager = self.app.global_shortcut();
+        if manager.is_registered(escape) {
+            return;
+        }
+        match manager.register(escape) {
+            Ok(()) => {
+                self.escape = Some(escape);
+                *KEYS.lock().unwrap() = vec![escape];
+            }
+            Err(e) => eprintln!("Failed to register Escape: {}", e),
+        }
+    }
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<HoldEvent>) {
+    let mut session = HoldSession::new(app);
+
+    for event in events {
+        match event {
+            HoldEvent::Press(shortcut, action) => session.press(shortcut, action),
+            HoldEvent::Release(shortcut) => session.release(shortcut),
+            HoldEvent::Cancel => session.end(),
+        }
+    }
+}
+
+/// Start previewing `actions` if hotkeys are held to preview. Returns whether the
+/// actions are left to run on release.
+pub fn hold(app: &tauri::AppHandle, shortcut: &Shortcut, actions: &[LayoutAction]) -> bool {
+    let enabled = cache::settings(app)
+        .map(|settings| settings.hold_to_preview)
+        .unwrap_or(false);
+    // Macros run right away, a preview would only show one of their steps
+    let [action] = actions else {
+        return false;
+    };
+    if !enabled || !can_preview(action) {
+        return false;
+    }
+
+    match EVENTS.get() {
+        Some(events) => events
+            .send(HoldEvent::Press(*shortcut, action.clone()))
+            .is_ok(),
+        None => false,
+    }
+}
+
+/// Apply the held hotkey if `shortcut` is the one being held
+pub fn release(shortcut: &Shortcut) {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(HoldEvent::Release(*shortcut));
+    }
+}
+
+/// Cancel the held hotkey on Escape. Returns whether `shortcut` was taken for that.
+pub fn handle_key(shortcut: &Shortcut) -> bool {
+    if !KEYS.lock().unwrap().contains(shortcut) {
+        return false;
+    }
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(HoldEvent::Cancel);
+    }
+    true
+}
+
+/// Start the hold thread
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender).is_err() {
+        return;
+    }
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+}
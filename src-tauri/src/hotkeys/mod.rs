@@ -0,0 +1,490 @@
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::snapping::snap_window_target;
+use crate::store::hotkey_options::{
+    self, hotkey_options, remove_hotkey_options, HotkeyOptions, WindowTarget,
+};
+use crate::store::hotkeys::{
+    hotkey_bindings, hotkey_entries, read_actions, HotkeyBinding, HOTKEYS_STORE_NAME,
+};
+use crate::store::settings::SettingsStore;
+use crate::store::zone_layouts;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_store::StoreExt;
+use tauri::Emitter;
+
+pub mod chords;
+pub mod conflicts;
+pub mod hold;
+pub mod presets;
+pub mod profiles;
+pub mod recorder;
+pub mod repeat;
+pub mod scope;
+pub mod window_mode;
+
+use conflicts::{ConflictKind, HotkeyConflict, HotkeyReport};
+
+/// Emitted with a `HotkeyReport` once the stored hotkeys are registered at startup, and
+/// again whenever other hotkeys become inactive or active
+pub const HOTKEY_REPORT_EVENT: &str = "hotkey-report";
+
+/// The last report, for the reasons registering failed at startup
+static REPORT: Mutex<HotkeyReport> = Mutex::new(HotkeyReport {
+    active: Vec::new(),
+    inactive: Vec::new(),
+});
+
+fn parse_shortcut(shortcut: &str) -> Result<Shortcut> {
+    Shortcut::from_str(shortcut)
+        .map_err(|e| CrossZonesError::Validation(format!("Invalid shortcut {}: {}", shortcut, e)))
+}
+
+fn register_hotkey(app: &tauri::AppHandle, shortcut: Shortcut) -> Result<()> {
+    let shortcut_manager = app.global_shortcut();
+
+    shortcut_manager.register(shortcut)?;
+
+    Ok(())
+}
+
+fn persist_hotkey_actions(
+    app: &tauri::AppHandle,
+    shortcut: Shortcut,
+    actions: &[LayoutAction],
+) -> Result<()> {
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+
+    store.set(shortcut.to_string(), serde_json::to_value(actions)?);
+
+    store.save()?;
+
+    Ok(())
+}
+
+/// Remove the binding for a shortcut and unregister it
+pub fn remove_hotkey(app: &tauri::AppHandle, shortcut: &str) -> Result<()> {
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+    store.delete(shortcut);
+    store.save()?;
+    remove_hotkey_options(app, shortcut)?;
+
+    let shortcut = parse_shortcut(shortcut)?;
+    let shortcut_manager = app.global_shortcut();
+    if shortcut_manager.is_registered(shortcut) {
+        shortcut_manager.unregister(shortcut)?;
+    }
+
+    refresh_report(app);
+    Ok(())
+}
+
+/// Register every stored hotkey that doesn't conflict with another binding, the OS
+/// or another app, and report the ones that stay inactive
+pub fn load_hotkeys(app: &tauri::AppHandle) -> Result<HotkeyReport> {
+    let (bindings, mut inactive) = conflicts::check_bindings(hotkey_entries(app)?);
+
+    let mut active = Vec::new();
+    for (shortcut, _actions) in bindings {
+        match register_hotkey(app, shortcut) {
+            Ok(()) => active.push(shortcut.to_string()),
+            Err(e) => inactive.push(HotkeyConflict::new(
+                shortcut.to_string(),
+                ConflictKind::Unavailable,
+                format!("Could not register {}: {}", shortcut, e),
+            )),
+        }
+    }
+
+    Ok(HotkeyReport { active, inactive })
+}
+
+/// Tell the user which hotkeys are inactive, both in the app and with a notification
+fn publish_report(app: &tauri::AppHandle, report: HotkeyReport) {
+    for conflict in &report.inactive {
+        eprintln!("Hotkey {} is inactive: {}", conflict.shortcut, conflict.message);
+    }
+
+    let _ = app.emit(HOTKEY_REPORT_EVENT, &report);
+
+    if !report.inactive.is_empty() {
+        let body = report
+            .inactive
+            .iter()
+            .map(|conflict| conflict.message.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let _ = app
+            .notification()
+            .builder()
+            .title(match report.inactive.len() {
+                1 => "1 hotkey is inactive".to_string(),
+                n => format!("{} hotkeys are inactive", n),
+            })
+            .body(body)
+            .show();
+    }
+
+    *REPORT.lock().unwrap() = report;
+}
+
+/// The stored hotkeys as they are registered right now. Hotkeys that are still not
+/// registered keep the reason from the last report.
+fn current_report(app: &tauri::AppHandle) -> Result<HotkeyReport> {
+    let (bindings, mut inactive) = conflicts::check_bindings(hotkey_entries(app)?);
+    let previous = REPORT.lock().unwrap_or_else(|e| e.into_inner()).clone();
+
+    let manager = app.global_shortcut();
+    let mut active = Vec::new();
+    for (shortcut, _actions) in bindings {
+        let key = shortcut.to_string();
+        if manager.is_registered(shortcut) {
+            active.push(key);
+            continue;
+        }
+
+        let message = previous
+            .inactive
+            .iter()
+            .find(|conflict| conflict.shortcut == key && conflict.kind == ConflictKind::Unavailable)
+            .map(|conflict| conflict.message.clone())
+            .unwrap_or_else(|| format!("{} is not registered", key));
+        inactive.push(HotkeyConflict::new(key, ConflictKind::Unavailable, message));
+    }
+
+    Ok(HotkeyReport { active, inactive })
+}
+
+/// Update the report after the hotkeys changed, and tell the frontend if other hotkeys
+/// are inactive now
+pub fn refresh_report(app: &tauri::AppHandle) {
+    let report = match current_report(app) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Failed to check hotkeys: {}", e);
+            return;
+        }
+    };
+
+    let mut previous = REPORT.lock().unwrap_or_else(|e| e.into_inner());
+    let changed = previous.inactive.len() != report.inactive.len()
+        || previous
+            .inactive
+            .iter()
+            .zip(&report.inactive)
+            .any(|(old, new)| old.shortcut != new.shortcut || old.kind != new.kind);
+    if changed {
+        let _ = app.emit(HOTKEY_REPORT_EVENT, &report);
+    }
+    *previous = report;
+}
+
+/// The actions stored for a shortcut, in order
+fn stored_actions(app: &tauri::AppHandle, shortcut: &Shortcut) -> Result<Vec<LayoutAction>> {
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+
+    let Some(value) = store.get(shortcut.to_string()) else {
+        return Ok(Vec::new());
+    };
+
+    read_actions(&value)
+        .map_err(|e| CrossZonesError::Store(format!("Hotkey {}: {}", shortcut, e)))
+}
+
+/// Run an action, with window actions acting on `target`
+fn run_action(
+    app: &tauri::AppHandle,
+    layout_action: &LayoutAction,
+    target: WindowTarget,
+) -> Result<()> {
+    // Handle ActivateLayout action separately
+    match layout_action {
+        LayoutAction::ActivateLayout { layout_id } => {
+            zone_layouts::set_active_zone_layout_id(app.clone(), Some(layout_id.clone()))?;
+
+            // Emit event to notify frontend of layout activation
+            let _ = app.emit("active-layout-changed", layout_id.clone());
+
+            // Show notification if enabled
+            if SettingsStore::new(app)?.get_show_layout_activation_notification()? {
+                // Get layout name for notification
+                if let Some(layout) = zone_layouts::get_zone_layout(app.clone(), layout_id.clone())? {
+                    let _ = app
+                        .notification()
+                        .builder()
+                        .title("Zone Layout Activated")
+                        .body(format!("Active layout: {}", layout.name))
+                        .show();
+                }
+            }
+        }
+        // Switching registers shortcuts, which can't happen in the shortcut handler
+        LayoutAction::ActivateHotkeyProfile { profile_id } => {
+            let app = app.clone();
+            let profile_id = profile_id.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = profiles::activate_profile(&app, &profile_id) {
+                    eprintln!("Failed to activate hotkey profile {}: {}", profile_id, e);
+                }
+            });
+        }
+        // Window mode registers keys, which can't happen in the shortcut handler
+        LayoutAction::WindowMode => window_mode::enter(),
+        _ => snap_window_target(layout_action.clone(), Some(app.clone()), target)?,
+    }
+
+    Ok(())
+}
+
+/// Run the shortcut's actions in order, stopping at the first one that fails. With
+/// hold to preview on, a single action runs on release instead. Move and resize
+/// actions repeat while the shortcut is held. Out of scope, the key goes to the
+/// focused application instead.
+fn handle_hotkey(app: &tauri::AppHandle, shortcut: &Shortcut) -> Result<()> {
+    if !scope::in_scope(app, shortcut) {
+        scope::pass_through(shortcut);
+        return Ok(());
+    }
+
+    let actions = stored_actions(app, shortcut)?;
+    let target = hotkey_options(app, &shortcut.to_string())?.target;
+    // Previews show the focused window, so hotkeys for other windows run right away
+    if target == WindowTarget::Focused && hold::hold(app, shortcut, &actions) {
+        return Ok(());
+    }
+
+    for layout_action in &actions {
+        run_action(app, layout_action, target)?;
+    }
+
+    repeat::start(app, shortcut, &actions, repeat::RepeatTarget::Hotkey(target));
+
+    Ok(())
+}
+
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let _ = app_handle.plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(move |app, hotkey, event| {
+                if event.state() == ShortcutState::Released {
+                    hold::release(hotkey);
+                    repeat::release(hotkey);
+                    return;
+                }
+                if chords::handle_key(hotkey)
+                    || window_mode::handle_key(hotkey)
+                    || hold::handle_key(hotkey)
+                {
+                    return;
+                }
+                // Errors are reported instead of panicking, which would stop all shortcuts
+                if let Err(e) = handle_hotkey(app, hotkey) {
+                    eprintln!("Hotkey {} failed: {}", hotkey, e);
+                }
+            })
+            .build(),
+    );
+
+    match load_hotkeys(app_handle) {
+        Ok(report) => publish_report(app_handle, report),
+        Err(e) => eprintln!("Failed to load hotkeys: {}", e),
+    }
+
+    chords::setup(app_handle);
+    window_mode::setup(app_handle);
+    hold::setup(app_handle);
+    repeat::setup(app_handle);
+    scope::setup(app_handle);
+}
+
+/// Why `shortcut` can't be bound to `actions`, given the stored entries, short of
+/// another app owning it, which only registering finds out
+pub fn check_hotkey(
+    shortcut: &Shortcut,
+    actions: &[LayoutAction],
+    entries: &[(String, serde_json::Value)],
+) -> Result<()> {
+    if actions.is_empty() {
+        return Err(CrossZonesError::Validation(format!(
+            "{} needs at least one action",
+            shortcut
+        )));
+    }
+
+    if let Some(conflict) = conflicts::check_binding(shortcut, actions, entries) {
+        return Err(conflict.into());
+    }
+    if chords::is_chord_key(shortcut) {
+        return Err(CrossZonesError::Conflict(format!(
+            "{} is used for chords",
+            shortcut
+        )));
+    }
+    if window_mode::is_window_mode_key(shortcut) {
+        return Err(CrossZonesError::Conflict(format!(
+            "{} is in use while window mode is on",
+            shortcut
+        )));
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn register_hotkey_action(
+    app: tauri::AppHandle,
+    shortcut: String,
+    action: LayoutAction,
+) -> Result<()> {
+    register_hotkey_macro(app, shortcut, vec![action])
+}
+
+/// Bind a shortcut to several actions that run one after the other
+#[tauri::command]
+pub fn register_hotkey_macro(
+    app: tauri::AppHandle,
+    shortcut: String,
+    actions: Vec<LayoutAction>,
+) -> Result<()> {
+    let shortcut = parse_shortcut(&shortcut)?;
+    bind_hotkey(&app, shortcut, &actions, &hotkey_entries(&app)?)
+}
+
+/// Bind a shortcut to actions, replacing whatever it was bound to before but keeping its
+/// options. If the new binding can't be registered, the old one is kept.
+pub fn rebind_hotkey(
+    app: &tauri::AppHandle,
+    shortcut: &str,
+    actions: &[LayoutAction],
+) -> Result<()> {
+    let shortcut = parse_shortcut(shortcut)?;
+    // Already bound like this, e.g. by the config file on an earlier start
+    let unchanged = stored_actions(app, &shortcut).is_ok_and(|stored| stored == actions);
+    if unchanged && app.global_shortcut().is_registered(shortcut) {
+        return Ok(());
+    }
+
+    let key = shortcut.to_string();
+    let others: Vec<_> = hotkey_entries(app)?
+        .into_iter()
+        .filter(|(stored, _)| *stored != key)
+        .collect();
+
+    bind_hotkey(app, shortcut, actions, &others)
+}
+
+fn bind_hotkey(
+    app: &tauri::AppHandle,
+    shortcut: Shortcut,
+    actions: &[LayoutAction],
+    entries: &[(String, serde_json::Value)],
+) -> Result<()> {
+    check_hotkey(&shortcut, actions, entries)?;
+
+    // Register before saving, so a shortcut owned by another app is never stored
+    if !app.global_shortcut().is_registered(shortcut) {
+        register_hotkey(app, shortcut).map_err(|e| {
+            CrossZonesError::Conflict(format!(
+                "{} could not be registered, another application may be using it: {}",
+                shortcut, e
+            ))
+        })?;
+    }
+
+    persist_hotkey_actions(app, shortcut, actions)?;
+    refresh_report(app);
+    Ok(())
+}
+
+/// Remove the binding for exactly this shortcut. Other shortcuts for the same
+/// actions are kept.
+#[tauri::command]
+pub fn unregister_hotkey(app: tauri::AppHandle, shortcut: String) -> Result<()> {
+    remove_hotkey(&app, &shortcut)
+}
+
+#[tauri::command]
+pub fn get_hotkey_options(app: tauri::AppHandle, shortcut: String) -> Result<HotkeyOptions> {
+    hotkey_options(&app, &parse_shortcut(&shortcut)?.to_string())
+}
+
+/// Set where a bound shortcut works
+#[tauri::command]
+pub fn set_hotkey_options(
+    app: tauri::AppHandle,
+    shortcut: String,
+    options: HotkeyOptions,
+) -> Result<()> {
+    let shortcut = parse_shortcut(&shortcut)?;
+    if stored_actions(&app, &shortcut)?.is_empty() {
+        return Err(CrossZonesError::NotFound(format!("Hotkey {}", shortcut)));
+    }
+
+    hotkey_options::set_hotkey_options(&app, &shortcut.to_string(), &options)
+}
+
+/// Which stored hotkeys are registered right now, and why the others aren't
+#[tauri::command]
+pub fn get_hotkey_report(app: tauri::AppHandle) -> Result<HotkeyReport> {
+    let report = current_report(&app)?;
+    *REPORT.lock().unwrap_or_else(|e| e.into_inner()) = report.clone();
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn get_all_hotkeys(app: tauri::AppHandle) -> Result<Vec<HotkeyBinding>> {
+    hotkey_bindings(&app)
+}
+
+#[tauri::command]
+pub fn clear_all_hotkeys(app: tauri::AppHandle) -> Result<()> {
+    let shortcut_manager = app.global_shortcut();
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+
+    // Get all shortcuts before clearing
+    let entries = hotkey_entries(&app)?;
+
+    // Unregister all shortcuts and delete from store
+    for (shortcut_str, _) in entries.iter() {
+        // Unregister the shortcut
+        if let Ok(shortcut) = Shortcut::try_from(shortcut_str.clone()) {
+            let _ = shortcut_manager.unregister(shortcut);
+        }
+        // Delete entries one by one so the schema version is kept
+        store.delete(shortcut_str);
+        remove_hotkey_options(&app, shortcut_str)?;
+    }
+
+    store.save()?;
+
+    refresh_report(&app);
+    Ok(())
+}
+
+/// The stored bindings with their options, which clearing removes as well, to bind
+/// them again with `restore_hotkeys`
+pub fn saved_hotkeys(app: &tauri::AppHandle) -> Result<Vec<(HotkeyBinding, HotkeyOptions)>> {
+    hotkey_bindings(app)?
+        .into_iter()
+        .map(|binding| {
+            let options = hotkey_options(app, &binding.shortcut)?;
+            Ok((binding, options))
+        })
+        .collect()
+}
+
+/// Bind hotkeys from `saved_hotkeys` again, after they were cleared. Those that fail
+/// are logged, so the others still come back.
+pub fn restore_hotkeys(app: &tauri::AppHandle, saved: Vec<(HotkeyBinding, HotkeyOptions)>) {
+    for (HotkeyBinding { shortcut, actions }, options) in saved {
+        let restored = register_hotkey_macro(app.clone(), shortcut.clone(), actions)
+            .and_then(|()| hotkey_options::set_hotkey_options(app, &shortcut, &options));
+        if let Err(e) = restored {
+            eprintln!("Failed to restore hotkey {}: {}", shortcut, e);
+        }
+    }
+}
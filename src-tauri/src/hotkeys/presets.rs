@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use tauri_plugin_global_shortcut::Shortcut;
+use ts_rs::TS;
+
+use super::conflicts::{self, ConflictKind, HotkeyConflict, HotkeyReport};
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkeys::{hotkey_entries, HotkeyBinding};
+
+/// Cmd on macOS, Ctrl everywhere else
+const PRIMARY: &str = if cfg!(target_os = "macos") {
+    "Super"
+} else {
+    "Control"
+};
+
+/// Win+arrows belong to Windows itself, and Ctrl+arrows to Mission Control on macOS
+const SNAP: &str = if cfg!(target_os = "macos") {
+    "Control+Super"
+} else {
+    "Super+Alt"
+};
+
+/// What happens to the current hotkeys when a preset is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum PresetMode {
+    /// Keep the current hotkeys, preset bindings for shortcuts in use are left out
+    Merge,
+    /// Remove the current hotkeys first
+    Replace,
+}
+
+/// A built-in set of bindings, with the shortcuts for the current platform
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct HotkeyPreset {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub bindings: Vec<HotkeyBinding>,
+}
+
+fn bind(mods: &str, key: &str, action: LayoutAction) -> (String, LayoutAction) {
+    (format!("{}+{}", mods, key), action)
+}
+
+fn rectangle() -> Vec<(String, LayoutAction)> {
+    let mods = "Control+Alt";
+    vec![
+        bind(mods, "ArrowLeft", LayoutAction::LeftHalf),
+        bind(mods, "ArrowRight", LayoutAction::RightHalf),
+        bind(mods, "ArrowUp", LayoutAction::TopHalf),
+        bind(mods, "ArrowDown", LayoutAction::BottomHalf),
+        bind(mods, "KeyU", LayoutAction::TopLeft),
+        bind(mods, "KeyI", LayoutAction::TopRight),
+        bind(mods, "KeyJ", LayoutAction::BottomLeft),
+        bind(mods, "KeyK", LayoutAction::BottomRight),
+        bind(mods, "KeyD", LayoutAction::FirstThird),
+        bind(mods, "KeyF", LayoutAction::CenterThird),
+        bind(mods, "KeyG", LayoutAction::LastThird),
+        bind(mods, "KeyE", LayoutAction::FirstTwoThirds),
+        bind(mods, "KeyT", LayoutAction::LastTwoThirds),
+        bind(mods, "Enter", LayoutAction::Maximize),
+        bind(mods, "Shift+ArrowUp", LayoutAction::MaximizeHeight),
+        bind(mods, "Minus", LayoutAction::Smaller),
+        bind(mods, "Equal", LayoutAction::Larger),
+        bind(mods, "KeyC", LayoutAction::Center),
+        bind(mods, "Backspace", LayoutAction::Restore),
+        bind(mods, "Super+ArrowRight", LayoutAction::NextDisplay),
+        bind(mods, "Super+ArrowLeft", LayoutAction::PreviousDisplay),
+    ]
+}
+
+fn windows_snap() -> Vec<(String, LayoutAction)> {
+    vec![
+        bind(SNAP, "ArrowLeft", LayoutAction::LeftHalf),
+        bind(SNAP, "ArrowRight", LayoutAction::RightHalf),
+        bind(SNAP, "ArrowUp", LayoutAction::Maximize),
+        bind(SNAP, "ArrowDown", LayoutAction::Restore),
+        bind(SNAP, "Shift+ArrowLeft", LayoutAction::PreviousDisplay),
+        bind(SNAP, "Shift+ArrowRight", LayoutAction::NextDisplay),
+    ]
+}
+
+/// Rectangle without the bindings Magnet has no counterpart for
+fn magnet() -> Vec<(String, LayoutAction)> {
+    rectangle()
+        .into_iter()
+        .filter(|(_, action)| {
+            !matches!(
+                action,
+                LayoutAction::MaximizeHeight | LayoutAction::Smaller | LayoutAction::Larger
+            )
+        })
+        .collect()
+}
+
+fn vim() -> Vec<(String, LayoutAction)> {
+    let mods = &format!("{PRIMARY}+Alt");
+    vec![
+        bind(mods, "KeyH", LayoutAction::LeftHalf),
+        bind(mods, "KeyJ", LayoutAction::BottomHalf),
+        bind(mods, "KeyK", LayoutAction::TopHalf),
+        bind(mods, "KeyL", LayoutAction::RightHalf),
+        bind(mods, "KeyY", LayoutAction::TopLeft),
+        bind(mods, "KeyU", LayoutAction::TopRight),
+        bind(mods, "KeyB", LayoutAction::BottomLeft),
+        bind(mods, "KeyN", LayoutAction::BottomRight),
+        bind(mods, "Shift+KeyH", LayoutAction::MoveLeft),
+        bind(mods, "Shift+KeyJ", LayoutAction::MoveDown),
+        bind(mods, "Shift+KeyK", LayoutAction::MoveUp),
+        bind(mods, "Shift+KeyL", LayoutAction::MoveRight),
+        bind(mods, "KeyM", LayoutAction::Maximize),
+        bind(mods, "KeyC", LayoutAction::Center),
+        bind(mods, "KeyW", LayoutAction::WindowMode),
+        bind(mods, "Comma", LayoutAction::PreviousDisplay),
+        bind(mods, "Period", LayoutAction::NextDisplay),
+        bind(mods, "Backspace", LayoutAction::Restore),
+    ]
+}
+
+fn preset(
+    id: &str,
+    name: &str,
+    description: &str,
+    bindings: Vec<(String, LayoutAction)>,
+) -> HotkeyPreset {
+    HotkeyPreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        bindings: bindings
+            .into_iter()
+            // Stored the way the store writes shortcuts, so they compare as strings too
+            .map(|(shortcut, action)| HotkeyBinding {
+                shortcut: Shortcut::from_str(&shortcut)
+                    .map(|shortcut| shortcut.to_string())
+                    .unwrap_or(shortcut),
+                actions: vec![action],
+            })
+            .collect(),
+    }
+}
+
+/// Every built-in preset
+pub fn presets() -> Vec<HotkeyPreset> {
+    vec![
+        preset(
+            "rectangle",
+            "Rectangle",
+            "Ctrl+Alt with arrows for halves, U I J K for corners, D F G E T for thirds and - = to resize",
+            rectangle(),
+        ),
+        preset(
+            "windows-snap",
+            "Windows Snap",
+            "Arrows for halves, maximize and restore, with Shift to move between displays",
+            windows_snap(),
+        ),
+        preset(
+            "magnet",
+            "Magnet",
+            "Ctrl+Alt with arrows for halves, U I J K for corners and D F G E T for thirds",
+            magnet(),
+        ),
+        preset(
+            "vim",
+            "Vim",
+            "H J K L for halves, with Shift to move the window, and Y U B N for corners",
+            vim(),
+        ),
+    ]
+}
+
+fn find_preset(preset_id: &str) -> Result<HotkeyPreset> {
+    presets()
+        .into_iter()
+        .find(|preset| preset.id == preset_id)
+        .ok_or_else(|| CrossZonesError::NotFound(format!("Hotkey preset {}", preset_id)))
+}
+
+/// Preset bindings that can't be bound, given the hotkeys kept by `mode`
+fn preset_conflicts(
+    app: &tauri::AppHandle,
+    preset: &HotkeyPreset,
+    mode: PresetMode,
+) -> Result<Vec<HotkeyConflict>> {
+    let entries = match mode {
+        PresetMode::Merge => hotkey_entries(app)?,
+        PresetMode::Replace => Vec::new(),
+    };
+
+    Ok(preset
+        .bindings
+        .iter()
+        .filter_map(|binding| match Shortcut::from_str(&binding.shortcut) {
+            Ok(shortcut) => conflicts::check_binding(&shortcut, &binding.actions, &entries),
+            Err(e) => Some(HotkeyConflict::new(
+                &binding.shortcut,
+                ConflictKind::Invalid,
+                format!("Invalid shortcut {}: {}", binding.shortcut, e),
+            )),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn get_hotkey_presets() -> Vec<HotkeyPreset> {
+    presets()
+}
+
+/// The preset bindings that applying with `mode` would leave out, so the user can
+/// choose between merging and replacing
+#[tauri::command]
+pub fn check_hotkey_preset(
+    app: tauri::AppHandle,
+    preset_id: String,
+    mode: PresetMode,
+) -> Result<Vec<HotkeyConflict>> {
+    preset_conflicts(&app, &find_preset(&preset_id)?, mode)
+}
+
+/// Bind the preset's shortcuts. Bindings that conflict or can't be registered are
+/// skipped and reported as inactive. If none of them can be bound when replacing, the
+/// previous hotkeys are bound again.
+#[tauri::command]
+pub fn apply_hotkey_preset(
+    app: tauri::AppHandle,
+    preset_id: String,
+    mode: PresetMode,
+) -> Result<HotkeyReport> {
+    let preset = find_preset(&preset_id)?;
+
+    let previous = match mode {
+        PresetMode::Merge => Vec::new(),
+        PresetMode::Replace => {
+            let previous = super::saved_hotkeys(&app)?;
+            super::clear_all_hotkeys(app.clone())?;
+            previous
+        }
+    };
+
+    let mut inactive = preset_conflicts(&app, &preset, mode)?;
+    let mut active = Vec::new();
+    for binding in preset.bindings {
+        if inactive
+            .iter()
+            .any(|conflict| conflict.shortcut == binding.shortcut)
+        {
+            continue;
+        }
+
+        match super::register_hotkey_macro(app.clone(), binding.shortcut.clone(), binding.actions) {
+            Ok(()) => active.push(binding.shortcut),
+            Err(e) => inactive.push(HotkeyConflict::new(
+                &binding.shortcut,
+                ConflictKind::Unavailable,
+                e.to_string(),
+            )),
+        }
+    }
+
+    if active.is_empty() {
+        super::restore_hotkeys(&app, previous);
+    }
+
+    super::refresh_report(&app);
+    Ok(HotkeyReport { active, inactive })
+}
@@ -0,0 +1,218 @@
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+use tauri::Emitter;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+use tauri_plugin_store::StoreExt;
+
+use super::conflicts;
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkey_profiles::{
+    active_profile_id, live_hotkeys, save_profiles, set_active_profile_id, stored_profiles,
+    HotkeyProfile,
+};
+use crate::store::hotkeys::{hotkey_entries, HOTKEYS_STORE_NAME};
+use crate::store::zone_layouts::generate_id;
+use crate::tray;
+
+/// Emitted with the profile id once another hotkey profile is active
+pub const HOTKEY_PROFILE_CHANGED_EVENT: &str = "hotkey-profile-changed";
+
+/// Held while switching, so two switches never interleave their registrations
+static SWITCHING: Mutex<()> = Mutex::new(());
+
+/// Unregister `registered` and register `previous` again
+fn restore_shortcuts(app: &tauri::AppHandle, registered: &[Shortcut], previous: &[Shortcut]) {
+    let manager = app.global_shortcut();
+    let _ = manager.unregister_multiple(registered.to_vec());
+    for shortcut in previous {
+        if let Err(e) = manager.register(*shortcut) {
+            eprintln!("Failed to register {} again: {}", shortcut, e);
+        }
+    }
+}
+
+/// Swap the registered shortcuts for the ones in `hotkeys`, returning them. Either all
+/// of them are registered, or the previous shortcuts are registered again and an error
+/// is returned.
+fn swap_shortcuts(
+    app: &tauri::AppHandle,
+    previous: &[Shortcut],
+    hotkeys: &BTreeMap<String, Vec<LayoutAction>>,
+) -> Result<Vec<Shortcut>> {
+    let entries: Vec<(String, JsonValue)> = hotkeys
+        .iter()
+        .map(|(shortcut, actions)| Ok((shortcut.clone(), serde_json::to_value(actions)?)))
+        .collect::<Result<_>>()?;
+    let (bindings, inactive) = conflicts::check_bindings(entries);
+    if let Some(conflict) = inactive.into_iter().next() {
+        return Err(conflict.into());
+    }
+
+    let manager = app.global_shortcut();
+    // Leader, chord and window mode keys stay registered, so they can't be taken over
+    if let Some((shortcut, _)) = bindings
+        .iter()
+        .find(|(shortcut, _)| !previous.contains(shortcut) && manager.is_registered(*shortcut))
+    {
+        return Err(CrossZonesError::Conflict(format!(
+            "{} is already in use",
+            shortcut
+        )));
+    }
+
+    manager.unregister_multiple(previous.to_vec())?;
+    // A held key of the previous profile won't be released to stop its repeat
+    for shortcut in previous {
+        super::repeat::release(shortcut);
+    }
+
+    let mut registered = Vec::new();
+    for (shortcut, _) in &bindings {
+        if let Err(e) = manager.register(*shortcut) {
+            restore_shortcuts(app, &registered, previous);
+            return Err(CrossZonesError::Conflict(format!(
+                "{} could not be registered, another application may be using it: {}",
+                shortcut, e
+            )));
+        }
+        registered.push(*shortcut);
+    }
+
+    Ok(registered)
+}
+
+/// Make `hotkeys` the only entries of the hotkeys store. Entries are replaced one by
+/// one so the schema version is kept.
+fn write_live_hotkeys(
+    app: &tauri::AppHandle,
+    hotkeys: &BTreeMap<String, Vec<LayoutAction>>,
+) -> Result<()> {
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+    // Unreadable entries too, so they don't end up in the other profile
+    for (shortcut, _) in hotkey_entries(app)? {
+        store.delete(shortcut);
+    }
+    for (shortcut, actions) in hotkeys {
+        store.set(shortcut.clone(), serde_json::to_value(actions)?);
+    }
+    store.save()?;
+    Ok(())
+}
+
+/// Make another profile's hotkeys the registered ones, keeping the current hotkeys in the
+/// profile being left
+pub fn activate_profile(app: &tauri::AppHandle, profile_id: &str) -> Result<()> {
+    let _switching = SWITCHING.lock().unwrap_or_else(|e| e.into_inner());
+
+    let current_id = active_profile_id(app)?;
+    if current_id == profile_id {
+        return Ok(());
+    }
+
+    let mut profiles = stored_profiles(app)?;
+    let target = profiles
+        .iter()
+        .find(|profile| profile.id == profile_id)
+        .cloned()
+        .ok_or_else(|| CrossZonesError::NotFound(format!("Hotkey profile {}", profile_id)))?;
+
+    let current = live_hotkeys(app)?;
+    let manager = app.global_shortcut();
+    // Hotkeys left inactive at startup aren't registered
+    let previous: Vec<Shortcut> = current
+        .keys()
+        .filter_map(|shortcut| Shortcut::from_str(shortcut).ok())
+        .filter(|shortcut| manager.is_registered(*shortcut))
+        .collect();
+
+    let registered = swap_shortcuts(app, &previous, &target.hotkeys)?;
+
+    if let Some(profile) = profiles.iter_mut().find(|profile| profile.id == current_id) {
+        profile.hotkeys = current.clone();
+    }
+    // The stores are written before the active id, so if any write fails the previous
+    // profile is still active and gets its shortcuts and hotkeys back
+    let switched = save_profiles(app, &profiles)
+        .and_then(|()| write_live_hotkeys(app, &target.hotkeys))
+        .and_then(|()| set_active_profile_id(app, profile_id));
+    if let Err(e) = switched {
+        restore_shortcuts(app, &registered, &previous);
+        if let Err(e) = write_live_hotkeys(app, &current) {
+            eprintln!("Failed to restore the current profile's hotkeys: {}", e);
+        }
+        return Err(e);
+    }
+
+    let _ = app.emit(HOTKEY_PROFILE_CHANGED_EVENT, profile_id);
+    tray::update_tray_menu(app);
+    super::refresh_report(app);
+
+    Ok(())
+}
+
+/// Every profile, the active one with the hotkeys registered right now
+#[tauri::command]
+pub fn get_hotkey_profiles(app: tauri::AppHandle) -> Result<Vec<HotkeyProfile>> {
+    let active_id = active_profile_id(&app)?;
+    let mut profiles = stored_profiles(&app)?;
+
+    if let Some(profile) = profiles.iter_mut().find(|profile| profile.id == active_id) {
+        profile.hotkeys = live_hotkeys(&app)?;
+    }
+
+    Ok(profiles)
+}
+
+#[tauri::command]
+pub fn get_active_hotkey_profile_id(app: tauri::AppHandle) -> Result<String> {
+    active_profile_id(&app)
+}
+
+/// Create a profile starting out with a copy of the current hotkeys
+#[tauri::command]
+pub fn create_hotkey_profile(app: tauri::AppHandle, name: String) -> Result<HotkeyProfile> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(CrossZonesError::Validation(
+            "A hotkey profile needs a name".to_string(),
+        ));
+    }
+
+    let mut profiles = stored_profiles(&app)?;
+    let profile = HotkeyProfile {
+        id: generate_id("hotkey-profile"),
+        name,
+        hotkeys: live_hotkeys(&app)?,
+    };
+    profiles.push(profile.clone());
+    save_profiles(&app, &profiles)?;
+
+    tray::update_tray_menu(&app);
+
+    Ok(profile)
+}
+
+#[tauri::command]
+pub fn delete_hotkey_profile(app: tauri::AppHandle, profile_id: String) -> Result<()> {
+    if active_profile_id(&app)? == profile_id {
+        return Err(CrossZonesError::Validation(
+            "The active hotkey profile can't be deleted".to_string(),
+        ));
+    }
+
+    let mut profiles = stored_profiles(&app)?;
+    profiles.retain(|profile| profile.id != profile_id);
+    save_profiles(&app, &profiles)?;
+
+    tray::update_tray_menu(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn activate_hotkey_profile(app: tauri::AppHandle, profile_id: String) -> Result<()> {
+    activate_profile(&app, &profile_id)
+}
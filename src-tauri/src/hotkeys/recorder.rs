@@ -0,0 +1,284 @@
+use rdev::{EventType, Key};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut};
+
+use crate::error::{CrossZonesError, Result};
+
+/// How long `record_next_shortcut` waits when no timeout is given
+const DEFAULT_TIMEOUT_MS: u32 = 10_000;
+
+/// How long a new keyboard grab gets to fail before it counts as grabbing
+const LISTEN_START_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Receives key events while a shortcut is being recorded, with the number of the recording
+static RECORDER: Mutex<Option<(u64, Sender<EventType>)>> = Mutex::new(None);
+
+static RECORDING_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Keys pressed while recording, whose release is swallowed too, also once the
+/// recording is over
+static SWALLOWED: Mutex<Vec<Key>> = Mutex::new(Vec::new());
+
+/// Set if the keyboard grab stopped, e.g. because the OS refused it
+static LISTEN_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+static LISTENER: Once = Once::new();
+
+/// Pass key events on to the recording if there is one. Returns whether the event is
+/// swallowed, so the keys pressed for a recording neither reach the focused app nor
+/// fire the hotkeys they are bound to.
+fn record_event(event_type: EventType) -> bool {
+    let recorder = RECORDER.lock().unwrap();
+    let mut swallowed = SWALLOWED.lock().unwrap();
+
+    match event_type {
+        EventType::KeyPress(key) => {
+            let Some((_, recorder)) = recorder.as_ref() else {
+                return false;
+            };
+            let _ = recorder.send(event_type);
+            if !swallowed.contains(&key) {
+                swallowed.push(key);
+            }
+            true
+        }
+        EventType::KeyRelease(key) => {
+            if let Some((_, recorder)) = recorder.as_ref() {
+                let _ = recorder.send(event_type);
+            }
+            // Keys held since before the recording are released as usual
+            match swallowed.iter().position(|pressed| *pressed == key) {
+                Some(index) => {
+                    swallowed.remove(index);
+                    true
+                }
+                None => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Start grabbing the keyboard. rdev can't stop grabbing, so there is a single grab for
+/// the lifetime of the app that lets every event through except while recording.
+fn start_listener() {
+    LISTENER.call_once(|| {
+        let (failed, failure) = mpsc::channel();
+        thread::spawn(move || {
+            let result = rdev::grab(|event| {
+                if record_event(event.event_type) {
+                    None
+                } else {
+                    Some(event)
+                }
+            });
+            if let Err(e) = result {
+                eprintln!("Keyboard grab failed: {:?}", e);
+                *LISTEN_ERROR.lock().unwrap() = Some(format!("{:?}", e));
+                let _ = failed.send(());
+            }
+        });
+
+        // rdev only returns if it can't grab, which the OS tells it right away. Until
+        // then the first recording waits, so it fails instead of never getting a key.
+        let _ = failure.recv_timeout(LISTEN_START_TIMEOUT);
+    });
+}
+
+fn modifier(key: Key) -> Option<Modifiers> {
+    match key {
+        Key::ShiftLeft | Key::ShiftRight => Some(Modifiers::SHIFT),
+        Key::ControlLeft | Key::ControlRight => Some(Modifiers::CONTROL),
+        Key::Alt | Key::AltGr => Some(Modifiers::ALT),
+        Key::MetaLeft | Key::MetaRight => Some(Modifiers::SUPER),
+        _ => None,
+    }
+}
+
+/// rdev keys and the `Code` they stand for, for keys that can end a shortcut
+const KEY_CODES: &[(Key, Code)] = &[
+    (Key::KeyA, Code::KeyA),
+    (Key::KeyB, Code::KeyB),
+    (Key::KeyC, Code::KeyC),
+    (Key::KeyD, Code::KeyD),
+    (Key::KeyE, Code::KeyE),
+    (Key::KeyF, Code::KeyF),
+    (Key::KeyG, Code::KeyG),
+    (Key::KeyH, Code::KeyH),
+    (Key::KeyI, Code::KeyI),
+    (Key::KeyJ, Code::KeyJ),
+    (Key::KeyK, Code::KeyK),
+    (Key::KeyL, Code::KeyL),
+    (Key::KeyM, Code::KeyM),
+    (Key::KeyN, Code::KeyN),
+    (Key::KeyO, Code::KeyO),
+    (Key::KeyP, Code::KeyP),
+    (Key::KeyQ, Code::KeyQ),
+    (Key::KeyR, Code::KeyR),
+    (Key::KeyS, Code::KeyS),
+    (Key::KeyT, Code::KeyT),
+    (Key::KeyU, Code::KeyU),
+    (Key::KeyV, Code::KeyV),
+    (Key::KeyW, Code::KeyW),
+    (Key::KeyX, Code::KeyX),
+    (Key::KeyY, Code::KeyY),
+    (Key::KeyZ, Code::KeyZ),
+    (Key::Num0, Code::Digit0),
+    (Key::Num1, Code::Digit1),
+    (Key::Num2, Code::Digit2),
+    (Key::Num3, Code::Digit3),
+    (Key::Num4, Code::Digit4),
+    (Key::Num5, Code::Digit5),
+    (Key::Num6, Code::Digit6),
+    (Key::Num7, Code::Digit7),
+    (Key::Num8, Code::Digit8),
+    (Key::Num9, Code::Digit9),
+    (Key::F1, Code::F1),
+    (Key::F2, Code::F2),
+    (Key::F3, Code::F3),
+    (Key::F4, Code::F4),
+    (Key::F5, Code::F5),
+    (Key::F6, Code::F6),
+    (Key::F7, Code::F7),
+    (Key::F8, Code::F8),
+    (Key::F9, Code::F9),
+    (Key::F10, Code::F10),
+    (Key::F11, Code::F11),
+    (Key::F12, Code::F12),
+    (Key::LeftArrow, Code::ArrowLeft),
+    (Key::RightArrow, Code::ArrowRight),
+    (Key::UpArrow, Code::ArrowUp),
+    (Key::DownArrow, Code::ArrowDown),
+    (Key::Return, Code::Enter),
+    (Key::Escape, Code::Escape),
+    (Key::Space, Code::Space),
+    (Key::Tab, Code::Tab),
+    (Key::Backspace, Code::Backspace),
+    (Key::Delete, Code::Delete),
+    (Key::Insert, Code::Insert),
+    (Key::Home, Code::Home),
+    (Key::End, Code::End),
+    (Key::PageUp, Code::PageUp),
+    (Key::PageDown, Code::PageDown),
+    (Key::Minus, Code::Minus),
+    (Key::Equal, Code::Equal),
+    (Key::LeftBracket, Code::BracketLeft),
+    (Key::RightBracket, Code::BracketRight),
+    (Key::SemiColon, Code::Semicolon),
+    (Key::Quote, Code::Quote),
+    (Key::BackSlash, Code::Backslash),
+    (Key::IntlBackslash, Code::IntlBackslash),
+    (Key::Comma, Code::Comma),
+    (Key::Dot, Code::Period),
+    (Key::Slash, Code::Slash),
+    (Key::BackQuote, Code::Backquote),
+    (Key::Kp0, Code::Numpad0),
+    (Key::Kp1, Code::Numpad1),
+    (Key::Kp2, Code::Numpad2),
+    (Key::Kp3, Code::Numpad3),
+    (Key::Kp4, Code::Numpad4),
+    (Key::Kp5, Code::Numpad5),
+    (Key::Kp6, Code::Numpad6),
+    (Key::Kp7, Code::Numpad7),
+    (Key::Kp8, Code::Numpad8),
+    (Key::Kp9, Code::Numpad9),
+    (Key::KpMinus, Code::NumpadSubtract),
+    (Key::KpPlus, Code::NumpadAdd),
+    (Key::KpMultiply, Code::NumpadMultiply),
+    (Key::KpDivide, Code::NumpadDivide),
+    (Key::KpReturn, Code::NumpadEnter),
+    (Key::KpDelete, Code::NumpadDecimal),
+    (Key::PrintScreen, Code::PrintScreen),
+    (Key::ScrollLock, Code::ScrollLock),
+    (Key::Pause, Code::Pause),
+];
+
+fn key_code(key: Key) -> Option<Code> {
+    KEY_CODES
+        .iter()
+        .find(|(known, _)| *known == key)
+        .map(|(_, code)| *code)
+}
+
+/// The rdev key for a shortcut's key, to send it on to another application
+pub fn rdev_key(code: Code) -> Option<Key> {
+    KEY_CODES
+        .iter()
+        .find(|(_, known)| *known == code)
+        .map(|(key, _)| *key)
+}
+
+/// Wait for modifiers followed by a key. `None` if Escape is pressed on its own, the
+/// time runs out or the recording is replaced or cancelled.
+fn record(timeout: Duration) -> Result<Option<Shortcut>> {
+    start_listener();
+    if let Some(e) = LISTEN_ERROR.lock().unwrap().clone() {
+        return Err(CrossZonesError::Platform(format!(
+            "Can't listen to the keyboard: {}",
+            e
+        )));
+    }
+
+    let (sender, events) = mpsc::channel();
+    let recording = RECORDING_COUNT.fetch_add(1, Ordering::Relaxed);
+    // A newer recording replaces the previous one, which then ends with `None`
+    *RECORDER.lock().unwrap() = Some((recording, sender));
+
+    let deadline = Instant::now() + timeout;
+    let mut mods = Modifiers::empty();
+    let shortcut = loop {
+        let event = match events.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break None,
+        };
+
+        match event {
+            EventType::KeyPress(key) => {
+                if let Some(modifier) = modifier(key) {
+                    mods |= modifier;
+                } else if key == Key::Escape && mods.is_empty() {
+                    break None;
+                } else if let Some(code) = key_code(key) {
+                    break Some(Shortcut::new(Some(mods), code));
+                }
+            }
+            EventType::KeyRelease(key) => {
+                if let Some(modifier) = modifier(key) {
+                    mods.remove(modifier);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    // Only stop recording if a newer recording hasn't taken over
+    let mut recorder = RECORDER.lock().unwrap();
+    if recorder.as_ref().is_some_and(|(current, _)| *current == recording) {
+        *recorder = None;
+    }
+
+    Ok(shortcut)
+}
+
+/// Record the next shortcut pressed anywhere, including combos with the Windows or
+/// Super key that the webview never sees. Resolves to `None` on Escape or timeout.
+#[tauri::command]
+pub async fn record_next_shortcut(timeout_ms: Option<u32>) -> Result<Option<String>> {
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS) as u64);
+
+    let shortcut = tauri::async_runtime::spawn_blocking(move || record(timeout))
+        .await
+        .map_err(|e| CrossZonesError::Platform(format!("Recording failed: {}", e)))??;
+
+    Ok(shortcut.map(|shortcut| shortcut.to_string()))
+}
+
+/// End a recording in progress, which then resolves to `None`
+#[tauri::command]
+pub fn cancel_shortcut_recording() {
+    *RECORDER.lock().unwrap() = None;
+}
@@ -0,0 +1,168 @@
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri_plugin_global_shortcut::Shortcut;
+
+use crate::snapping::action::LayoutAction;
+use crate::store::cache;
+use crate::store::hotkey_options::WindowTarget;
+use crate::store::settings::Settings;
+
+static EVENTS: OnceLock<Sender<RepeatEvent>> = OnceLock::new();
+
+enum RepeatEvent {
+    Start(Shortcut, Vec<LayoutAction>, RepeatTarget),
+    Release(Shortcut),
+    Stop,
+}
+
+/// The window a repeat acts on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatTarget {
+    /// The window the hotkey targets, looked up again on every repeat
+    Hotkey(WindowTarget),
+    /// The window window mode started with. Only the window mode thread holds on to it,
+    /// so repeats are passed on to there.
+    WindowMode,
+}
+
+/// Whether running `action` again moves the window further, so it makes sense to repeat
+pub fn is_repeatable(action: &LayoutAction) -> bool {
+    matches!(
+        action,
+        LayoutAction::MoveLeft
+            | LayoutAction::MoveRight
+            | LayoutAction::MoveUp
+            | LayoutAction::MoveDown
+            | LayoutAction::MoveBy { .. }
+            | LayoutAction::ResizeBy { .. }
+            | LayoutAction::Smaller
+            | LayoutAction::Larger
+    )
+}
+
+/// Each repeat comes a quarter sooner than the last, down to `min`
+pub fn next_interval(interval: Duration, min: Duration) -> Duration {
+    (interval * 3 / 4).max(min)
+}
+
+struct Repeat {
+    shortcut: Shortcut,
+    actions: Vec<LayoutAction>,
+    target: RepeatTarget,
+    interval: Duration,
+    next: Instant,
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<RepeatEvent>) {
+    let mut repeat: Option<Repeat> = None;
+
+    loop {
+        let event = match &repeat {
+            Some(repeat) => {
+                events.recv_timeout(repeat.next.saturating_duration_since(Instant::now()))
+            }
+            None => events.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match event {
+            Ok(RepeatEvent::Start(shortcut, actions, target)) => {
+                let settings = cache::settings(&app)
+                    .map(|settings| (*settings).clone())
+                    .unwrap_or_default();
+                repeat = Some(Repeat {
+                    shortcut,
+                    actions,
+                    target,
+                    interval: Duration::from_millis(settings.key_repeat_interval_ms as u64),
+                    next: Instant::now()
+                        + Duration::from_millis(settings.key_repeat_delay_ms as u64),
+                });
+            }
+            Ok(RepeatEvent::Release(shortcut)) => {
+                if repeat.as_ref().is_some_and(|r| r.shortcut == shortcut) {
+                    repeat = None;
+                }
+            }
+            Ok(RepeatEvent::Stop) => repeat = None,
+            Err(RecvTimeoutError::Timeout) => {
+                let Some(current) = &mut repeat else {
+                    continue;
+                };
+
+                let failed = match current.target {
+                    RepeatTarget::Hotkey(target) => current.actions.iter().find_map(|action| {
+                        super::run_action(&app, action, target)
+                            .err()
+                            .map(|e| (action.name(), e))
+                    }),
+                    RepeatTarget::WindowMode => {
+                        super::window_mode::repeat(&current.actions);
+                        None
+                    }
+                };
+                if let Some((name, e)) = failed {
+                    eprintln!("Repeating {} failed: {}", name, e);
+                    repeat = None;
+                    continue;
+                }
+
+                let min = cache::settings(&app)
+                    .map(|settings| settings.key_repeat_min_interval_ms)
+                    .unwrap_or(Settings::default().key_repeat_min_interval_ms);
+                current.interval =
+                    next_interval(current.interval, Duration::from_millis(min as u64));
+                // Counted from now rather than the last deadline, so slow moves don't pile up
+                current.next = Instant::now() + current.interval;
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Keep running `actions` on `target` while `shortcut` is held, if key repeat is on
+/// and every action is one that repeats
+pub fn start(
+    app: &tauri::AppHandle,
+    shortcut: &Shortcut,
+    actions: &[LayoutAction],
+    target: RepeatTarget,
+) {
+    let enabled = cache::settings(app)
+        .map(|settings| settings.key_repeat)
+        .unwrap_or(false);
+    if !enabled || actions.is_empty() || !actions.iter().all(is_repeatable) {
+        return;
+    }
+
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(RepeatEvent::Start(*shortcut, actions.to_vec(), target));
+    }
+}
+
+/// Stop repeating if `shortcut` is the one being repeated
+pub fn release(shortcut: &Shortcut) {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(RepeatEvent::Release(*shortcut));
+    }
+}
+
+/// Stop repeating whatever is being repeated, e.g. when its key is unregistered and
+/// won't be released
+pub fn stop() {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(RepeatEvent::Stop);
+    }
+}
+
+/// Start the repeat thread
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender).is_err() {
+        return;
+    }
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+}
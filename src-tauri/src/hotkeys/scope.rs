@@ -0,0 +1,105 @@
+use rdev::EventType;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+
+use super::recorder::rdev_key;
+use crate::store::cache;
+use crate::store::hotkey_options::hotkey_options;
+
+/// Time for the OS to deliver a sent key before the next one, and before the shortcut
+/// is registered again and would catch it
+const SEND_DELAY: Duration = Duration::from_millis(20);
+
+static EVENTS: OnceLock<Sender<Shortcut>> = OnceLock::new();
+
+// The focused application as app scopes list it: the executable on Windows and the
+// bundle id on macOS
+#[cfg(target_os = "windows")]
+use crate::window::windows::{get_foreground_executable as focused_app, is_fullscreen_app_focused};
+
+#[cfg(target_os = "macos")]
+use crate::window::macos::{get_frontmost_bundle_id as focused_app, is_fullscreen_app_focused};
+
+#[cfg(target_os = "linux")]
+fn focused_app() -> Option<String> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn is_fullscreen_app_focused() -> bool {
+    false
+}
+
+/// Whether `shortcut` should run its actions with the focused application, or be left
+/// to it. Hotkeys are left alone while a fullscreen app has focus, if that is turned on.
+pub fn in_scope(app: &tauri::AppHandle, shortcut: &Shortcut) -> bool {
+    let suppress_in_fullscreen = cache::settings(app)
+        .map(|settings| settings.suppress_hotkeys_in_fullscreen)
+        .unwrap_or(false);
+    if suppress_in_fullscreen && is_fullscreen_app_focused() {
+        return false;
+    }
+
+    match hotkey_options(app, &shortcut.to_string()) {
+        Ok(options) => options
+            .scope
+            .is_none_or(|scope| scope.allows(focused_app().as_deref())),
+        Err(e) => {
+            eprintln!("Failed to read options for {}: {}", shortcut, e);
+            true
+        }
+    }
+}
+
+/// Unregister the shortcut, send its key on to the focused application and register it
+/// again. The modifiers are still held by the user, so only the key itself is sent.
+fn send_on(app: &tauri::AppHandle, shortcut: Shortcut) {
+    let Some(key) = rdev_key(shortcut.key) else {
+        return;
+    };
+
+    let manager = app.global_shortcut();
+    if let Err(e) = manager.unregister(shortcut) {
+        eprintln!("Failed to pass {} through: {}", shortcut, e);
+        return;
+    }
+
+    for event in [EventType::KeyPress(key), EventType::KeyRelease(key)] {
+        if let Err(e) = rdev::simulate(&event) {
+            eprintln!("Failed to send {:?}: {:?}", event, e);
+        }
+        thread::sleep(SEND_DELAY);
+    }
+
+    if let Err(e) = manager.register(shortcut) {
+        eprintln!("Failed to register {} again: {}", shortcut, e);
+    }
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<Shortcut>) {
+    for shortcut in events {
+        send_on(&app, shortcut);
+    }
+}
+
+/// Let the focused application have the key of an out of scope shortcut. Like chords,
+/// this registers from its own thread, never from the shortcut handler.
+pub fn pass_through(shortcut: &Shortcut) {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(*shortcut);
+    }
+}
+
+/// Start the pass-through thread
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender).is_err() {
+        return;
+    }
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+}
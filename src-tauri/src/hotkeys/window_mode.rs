@@ -0,0 +1,271 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut};
+
+use super::repeat::{self, RepeatTarget};
+use crate::snapping::action::LayoutAction;
+use crate::snapping::window_state::{hold_window_states, release_window_states};
+use crate::snapping::{focused_window, snap_captured_window, CapturedWindow};
+
+/// How far an arrow key moves or resizes the window, in pixels
+const STEP: i32 = 20;
+
+/// The keys registered while window mode is on, so the shortcut handler knows what to
+/// pass on to the window mode thread
+static KEYS: Mutex<Vec<Shortcut>> = Mutex::new(Vec::new());
+
+static EVENTS: OnceLock<Sender<WindowModeEvent>> = OnceLock::new();
+
+enum WindowModeEvent {
+    Enter,
+    Key(Shortcut),
+    Repeat(Vec<LayoutAction>),
+}
+
+const ARROWS: [Code; 4] = [
+    Code::ArrowLeft,
+    Code::ArrowRight,
+    Code::ArrowUp,
+    Code::ArrowDown,
+];
+
+const DIGITS: [Code; 9] = [
+    Code::Digit1,
+    Code::Digit2,
+    Code::Digit3,
+    Code::Digit4,
+    Code::Digit5,
+    Code::Digit6,
+    Code::Digit7,
+    Code::Digit8,
+    Code::Digit9,
+];
+
+/// What a key does in window mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowModeKey {
+    Action(LayoutAction),
+    Commit,
+    Cancel,
+}
+
+/// Arrows move, Shift+arrows resize, digits apply zones of the active layout
+pub fn window_mode_key(shortcut: &Shortcut) -> Option<WindowModeKey> {
+    let (dx, dy) = match shortcut.key {
+        Code::ArrowLeft => (-STEP, 0),
+        Code::ArrowRight => (STEP, 0),
+        Code::ArrowUp => (0, -STEP),
+        Code::ArrowDown => (0, STEP),
+        Code::Enter if shortcut.mods.is_empty() => return Some(WindowModeKey::Commit),
+        Code::Escape if shortcut.mods.is_empty() => return Some(WindowModeKey::Cancel),
+        key if shortcut.mods.is_empty() => {
+            let zone_number = DIGITS.iter().position(|digit| *digit == key)? as u32 + 1;
+            return Some(WindowModeKey::Action(LayoutAction::ApplyZone {
+                zone_number,
+            }));
+        }
+        _ => return None,
+    };
+
+    if shortcut.mods.is_empty() {
+        Some(WindowModeKey::Action(LayoutAction::MoveBy { dx, dy }))
+    } else if shortcut.mods == Modifiers::SHIFT {
+        Some(WindowModeKey::Action(LayoutAction::ResizeBy {
+            width: dx,
+            height: dy,
+        }))
+    } else {
+        None
+    }
+}
+
+fn mode_keys() -> Vec<Shortcut> {
+    ARROWS
+        .iter()
+        .flat_map(|arrow| {
+            [
+                Shortcut::new(None, *arrow),
+                Shortcut::new(Some(Modifiers::SHIFT), *arrow),
+            ]
+        })
+        .chain(DIGITS.iter().map(|digit| Shortcut::new(None, *digit)))
+        .chain([
+            Shortcut::new(None, Code::Enter),
+            Shortcut::new(None, Code::Escape),
+        ])
+        .collect()
+}
+
+/// Window mode state, owned by the window mode thread. Like chords, keys are only
+/// registered from here, never from the shortcut handler.
+struct WindowModeSession {
+    app: tauri::AppHandle,
+    /// The window that was focused when the mode started, set while the mode is on.
+    /// Keys keep acting on it if another window takes the focus.
+    window: Option<CapturedWindow>,
+    /// Whether an action moved the window, so Esc has a frame to go back to
+    moved: bool,
+    registered: Vec<Shortcut>,
+}
+
+impl WindowModeSession {
+    fn new(app: tauri::AppHandle) -> Self {
+        Self {
+            app,
+            window: None,
+            moved: false,
+            registered: Vec::new(),
+        }
+    }
+
+    fn enter(&mut self) {
+        if self.window.is_some() {
+            return;
+        }
+        let Some(window) = focused_window() else {
+            eprintln!("Window mode needs a focused window");
+            return;
+        };
+        self.window = Some(window);
+        self.moved = false;
+
+        // `Restore` keeps pointing at the frame from before the mode, whatever moves follow
+        hold_window_states();
+        self.register_keys();
+    }
+
+    /// Run `action` on the mode's window, returning whether it did
+    fn apply(&mut self, action: &LayoutAction) -> bool {
+        let Some(window) = &self.window else {
+            return false;
+        };
+
+        match snap_captured_window(action.clone(), Some(self.app.clone()), window) {
+            Ok(()) => {
+                self.moved = true;
+                true
+            }
+            Err(e) => {
+                eprintln!("Window mode action {} failed: {}", action.name(), e);
+                false
+            }
+        }
+    }
+
+    fn press(&mut self, key: Shortcut) {
+        if self.window.is_none() {
+            return;
+        }
+
+        match window_mode_key(&key) {
+            Some(WindowModeKey::Action(action)) => {
+                if self.apply(&action) {
+                    // Holding an arrow keeps moving or resizing
+                    repeat::start(&self.app, &key, &[action], RepeatTarget::WindowMode);
+                }
+            }
+            Some(WindowModeKey::Commit) => self.exit(),
+            Some(WindowModeKey::Cancel) => {
+                if let (true, Some(window)) = (self.moved, &self.window) {
+                    let restore =
+                        snap_captured_window(LayoutAction::Restore, Some(self.app.clone()), window);
+                    if let Err(e) = restore {
+                        eprintln!("Failed to restore window: {}", e);
+                    }
+                }
+                self.exit();
+            }
+            None => {}
+        }
+    }
+
+    fn exit(&mut self) {
+        self.window = None;
+        // The held arrow's release never arrives once it is unregistered
+        repeat::stop();
+        release_window_states();
+        self.unregister_keys();
+    }
+
+    /// Register the mode's keys, leaving out those bound to a hotkey
+    fn register_keys(&mut self) {
+        let manager = self.app.global_shortcut();
+        for shortcut in mode_keys() {
+            if manager.is_registered(shortcut) {
+                continue;
+            }
+            match manager.register(shortcut) {
+                Ok(()) => self.registered.push(shortcut),
+                Err(e) => eprintln!("Failed to register window mode key {}: {}", shortcut, e),
+            }
+        }
+        *KEYS.lock().unwrap() = self.registered.clone();
+    }
+
+    fn unregister_keys(&mut self) {
+        let manager = self.app.global_shortcut();
+        for shortcut in self.registered.drain(..) {
+            let _ = manager.unregister(shortcut);
+        }
+        KEYS.lock().unwrap().clear();
+    }
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<WindowModeEvent>) {
+    let mut session = WindowModeSession::new(app);
+
+    for event in events {
+        match event {
+            WindowModeEvent::Enter => session.enter(),
+            WindowModeEvent::Key(key) => session.press(key),
+            WindowModeEvent::Repeat(actions) => {
+                for action in &actions {
+                    session.apply(action);
+                }
+            }
+        }
+    }
+}
+
+/// Start window mode for the focused window
+pub fn enter() {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(WindowModeEvent::Enter);
+    }
+}
+
+/// Run held keys' actions again on the mode's window, if the mode is still on
+pub fn repeat(actions: &[LayoutAction]) {
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(WindowModeEvent::Repeat(actions.to_vec()));
+    }
+}
+
+/// Whether `shortcut` was registered for window mode
+pub fn is_window_mode_key(shortcut: &Shortcut) -> bool {
+    KEYS.lock().unwrap().contains(shortcut)
+}
+
+/// Pass the window mode keys on to the window mode thread. Returns whether `shortcut`
+/// was one of them.
+pub fn handle_key(shortcut: &Shortcut) -> bool {
+    if !is_window_mode_key(shortcut) {
+        return false;
+    }
+    if let Some(events) = EVENTS.get() {
+        let _ = events.send(WindowModeEvent::Key(*shortcut));
+    }
+    true
+}
+
+/// Start the window mode thread
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender).is_err() {
+        return;
+    }
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::error::CrossZonesError;
+use crate::import::ImportIssue;
+use crate::store::zone_layouts::{self, Zone, ZoneLayout};
+
+/// FancyZones grid percentages are expressed in ten-thousandths
+const GRID_PERCENTAGE_SCALE: f64 = 10000.0;
+
+#[derive(Debug, Deserialize)]
+struct CustomLayoutsFile {
+    #[serde(rename = "custom-layouts")]
+    custom_layouts: Vec<CustomLayout>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomLayout {
+    uuid: String,
+    name: String,
+    #[serde(rename = "type")]
+    layout_type: String,
+    info: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CanvasInfo {
+    ref_width: f64,
+    ref_height: f64,
+    zones: Vec<CanvasZone>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CanvasZone {
+    #[serde(rename = "X")]
+    x: f64,
+    #[serde(rename = "Y")]
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct GridInfo {
+    rows: usize,
+    columns: usize,
+    rows_percentage: Vec<f64>,
+    columns_percentage: Vec<f64>,
+    cell_child_map: Vec<Vec<usize>>,
+    #[serde(default)]
+    show_spacing: bool,
+    #[serde(default)]
+    spacing: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LayoutImportReport {
+    pub layouts: Vec<ZoneLayout>,
+    pub issues: Vec<ImportIssue>,
+}
+
+/// FancyZones uuids look like `{0B8A...}`; strip the braces so the id is label-safe
+fn layout_id(uuid: &str) -> String {
+    format!(
+        "fancyzones-{}",
+        uuid.trim_matches(|c| c == '{' || c == '}').to_lowercase()
+    )
+}
+
+fn convert_canvas(
+    info: CanvasInfo,
+    name: &str,
+    issues: &mut Vec<ImportIssue>,
+) -> Result<Vec<Zone>, String> {
+    if info.ref_width <= 0.0 || info.ref_height <= 0.0 {
+        return Err("Canvas layout has no reference size".to_string());
+    }
+
+    let mut clipped = false;
+    let zones = info
+        .zones
+        .iter()
+        .enumerate()
+        .map(|(index, zone)| {
+            let x = zone.x / info.ref_width * 100.0;
+            let y = zone.y / info.ref_height * 100.0;
+            let right = (zone.x + zone.width) / info.ref_width * 100.0;
+            let bottom = (zone.y + zone.height) / info.ref_height * 100.0;
+
+            let (cx, cy) = (x.clamp(0.0, 100.0), y.clamp(0.0, 100.0));
+            let (cr, cb) = (right.clamp(0.0, 100.0), bottom.clamp(0.0, 100.0));
+            clipped |= (cx, cy, cr, cb) != (x, y, right, bottom);
+
+            Zone {
+                id: zone_layouts::generate_id("zone"),
+                x: cx,
+                y: cy,
+                width: cr - cx,
+                height: cb - cy,
+                number: index as u32 + 1,
+            }
+        })
+        .collect();
+
+    if clipped {
+        issues.push(ImportIssue::changed(
+            name,
+            "Zones extending beyond the screen were clipped",
+        ));
+    }
+
+    Ok(zones)
+}
+
+/// Turn cumulative grid percentages into edge positions in the 0–100 range
+fn grid_edges(percentages: &[f64]) -> Vec<f64> {
+    let total: f64 = percentages.iter().sum();
+    let scale = if total > 0.0 {
+        total
+    } else {
+        GRID_PERCENTAGE_SCALE
+    };
+
+    let mut edges = vec![0.0];
+    let mut sum = 0.0;
+    for percentage in percentages {
+        sum += percentage;
+        edges.push(sum / scale * 100.0);
+    }
+    edges
+}
+
+fn convert_grid(
+    info: GridInfo,
+    name: &str,
+    issues: &mut Vec<ImportIssue>,
+) -> Result<Vec<Zone>, String> {
+    if info.rows_percentage.len() != info.rows
+        || info.columns_percentage.len() != info.columns
+        || info.cell_child_map.len() != info.rows
+        || info.cell_child_map.iter().any(|row| row.len() != info.columns)
+    {
+        return Err("Grid dimensions do not match its cell map".to_string());
+    }
+
+    let row_edges = grid_edges(&info.rows_percentage);
+    let column_edges = grid_edges(&info.columns_percentage);
+    let zone_count = info
+        .cell_child_map
+        .iter()
+        .flatten()
+        .max()
+        .map_or(0, |max| max + 1);
+
+    let mut zones = Vec::new();
+    for index in 0..zone_count {
+        let cells: Vec<(usize, usize)> = info
+            .cell_child_map
+            .iter()
+            .enumerate()
+            .flat_map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, child)| **child == index)
+                    .map(move |(column, _)| (row, column))
+            })
+            .collect();
+
+        if cells.is_empty() {
+            continue;
+        }
+
+        let top = cells.iter().map(|(row, _)| *row).min().unwrap();
+        let bottom = cells.iter().map(|(row, _)| *row).max().unwrap();
+        let left = cells.iter().map(|(_, column)| *column).min().unwrap();
+        let right = cells.iter().map(|(_, column)| *column).max().unwrap();
+
+        // A zone must be a solid rectangle of cells
+        if cells.len() != (bottom - top + 1) * (right - left + 1) {
+            return Err(format!("Zone {} is not rectangular", index + 1));
+        }
+
+        zones.push(Zone {
+            id: zone_layouts::generate_id("zone"),
+            x: column_edges[left],
+            y: row_edges[top],
+            width: column_edges[right + 1] - column_edges[left],
+            height: row_edges[bottom + 1] - row_edges[top],
+            number: index as u32 + 1,
+        });
+    }
+
+    if info.show_spacing && info.spacing > 0 {
+        issues.push(ImportIssue::changed(
+            name,
+            format!("Zone spacing of {}px is not supported and was ignored", info.spacing),
+        ));
+    }
+
+    Ok(zones)
+}
+
+/// Convert the contents of a FancyZones `custom-layouts.json` into zone layouts
+pub fn convert_custom_layouts(json: &str) -> Result<LayoutImportReport, String> {
+    let file: CustomLayoutsFile = serde_json::from_str(json)
+        .map_err(|e| format!("Failed to parse FancyZones layouts: {}", e))?;
+
+    let mut layouts = Vec::new();
+    let mut issues = Vec::new();
+
+    for layout in file.custom_layouts {
+        let zones = match layout.layout_type.as_str() {
+            "canvas" => serde_json::from_value(layout.info)
+                .map_err(|e| format!("Invalid canvas layout: {}", e))
+                .and_then(|info| convert_canvas(info, &layout.name, &mut issues)),
+            "grid" => serde_json::from_value(layout.info)
+                .map_err(|e| format!("Invalid grid layout: {}", e))
+                .and_then(|info| convert_grid(info, &layout.name, &mut issues)),
+            other => Err(format!("Unsupported layout type \"{}\"", other)),
+        };
+
+        match zones {
+            Ok(zones) => layouts.push(ZoneLayout {
+                id: layout_id(&layout.uuid),
+                name: layout.name,
+                zones,
+                screen_width: None,
+                screen_height: None,
+                split_tree: None,
+            }),
+            Err(message) => issues.push(ImportIssue::skipped(layout.name, message)),
+        }
+    }
+
+    Ok(LayoutImportReport { layouts, issues })
+}
+
+/// Import all layouts from a FancyZones `custom-layouts.json` file. Layouts that fail
+/// validation are reported and skipped; re-importing the same file replaces earlier imports.
+#[tauri::command]
+pub fn import_fancyzones_layouts(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<LayoutImportReport, CrossZonesError> {
+    let json = std::fs::read_to_string(&path).map_err(|e| CrossZonesError::file(&path, e))?;
+    let report = convert_custom_layouts(&json).map_err(CrossZonesError::Validation)?;
+
+    let mut layouts = Vec::new();
+    let mut issues = report.issues;
+    for layout in report.layouts {
+        match zone_layouts::save_zone_layout(app.clone(), layout.clone()) {
+            Ok(()) => layouts.push(layout),
+            Err(e) => issues.push(ImportIssue::skipped(layout.name, e)),
+        }
+    }
+
+    Ok(LayoutImportReport { layouts, issues })
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tauri_plugin_global_shortcut::Shortcut;
+use ts_rs::TS;
+
+use crate::error::CrossZonesError;
+use crate::hotkeys;
+use crate::snapping::action::LayoutAction;
+
+pub mod fancyzones;
+pub mod rectangle;
+pub mod spectacle;
+
+/// Something an importer could not carry over as-is
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ImportIssue {
+    /// The imported entry the issue refers to, e.g. a layout name or shortcut
+    pub entry: String,
+    pub message: String,
+    /// Whether the entry was dropped entirely rather than imported with changes
+    pub skipped: bool,
+}
+
+impl ImportIssue {
+    pub fn skipped(entry: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            entry: entry.into(),
+            message: message.into(),
+            skipped: true,
+        }
+    }
+
+    pub fn changed(entry: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            entry: entry.into(),
+            message: message.into(),
+            skipped: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ImportedHotkey {
+    pub shortcut: String,
+    /// The action names in kebab-case, in the order they run
+    pub actions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HotkeyImportReport {
+    pub imported: Vec<ImportedHotkey>,
+    pub issues: Vec<ImportIssue>,
+}
+
+/// A shortcut binding read from another app's config, or the reason it can't be used
+pub struct ForeignBinding {
+    /// The other app's name for the action
+    pub name: String,
+    pub binding: Result<(String, Vec<LayoutAction>), String>,
+}
+
+/// Build a shortcut string from modifier names understood by `Shortcut::from_str` and a key
+pub fn shortcut_string(modifiers: &[&str], key: &str) -> Result<String, String> {
+    let mut tokens = modifiers.to_vec();
+    tokens.push(key);
+    let shortcut = Shortcut::from_str(&tokens.join("+"))
+        .map_err(|e| format!("Unsupported shortcut {}: {}", tokens.join("+"), e))?;
+    Ok(shortcut.to_string())
+}
+
+/// Register imported bindings through `register_hotkey_macro`. Shortcuts that are already
+/// bound to different actions, or used twice in the import, are reported and skipped.
+pub fn register_foreign_bindings(
+    app: &tauri::AppHandle,
+    bindings: Vec<ForeignBinding>,
+) -> Result<HotkeyImportReport, CrossZonesError> {
+    let mut imported = Vec::new();
+    let mut issues = Vec::new();
+    let mut seen: HashMap<String, String> = HashMap::new();
+
+    for ForeignBinding { name, binding } in bindings {
+        let (shortcut, actions) = match binding {
+            Ok(binding) => binding,
+            Err(message) => {
+                issues.push(ImportIssue::skipped(name, message));
+                continue;
+            }
+        };
+
+        if let Some(other) = seen.get(&shortcut) {
+            issues.push(ImportIssue::skipped(
+                name,
+                format!("{} is also used for {} in the imported config", shortcut, other),
+            ));
+            continue;
+        }
+        seen.insert(shortcut.clone(), name.clone());
+
+        // Rebinding a shortcut to the actions it already has succeeds without changes
+        let action_names = actions
+            .iter()
+            .map(|action| action.name().to_string())
+            .collect();
+        match hotkeys::register_hotkey_macro(app.clone(), shortcut.clone(), actions) {
+            Ok(()) => imported.push(ImportedHotkey {
+                shortcut,
+                actions: action_names,
+            }),
+            Err(e) => issues.push(ImportIssue::skipped(name, e)),
+        }
+    }
+
+    Ok(HotkeyImportReport { imported, issues })
+}
@@ -0,0 +1,195 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::error::CrossZonesError;
+use crate::import::{register_foreign_bindings, shortcut_string, ForeignBinding, HotkeyImportReport};
+use crate::snapping::action::LayoutAction;
+
+// NSEvent.ModifierFlags as stored by Rectangle
+const SHIFT_FLAG: u64 = 1 << 17;
+const CONTROL_FLAG: u64 = 1 << 18;
+const OPTION_FLAG: u64 = 1 << 19;
+const COMMAND_FLAG: u64 = 1 << 20;
+
+#[derive(Debug, Deserialize)]
+struct RectangleConfig {
+    #[serde(default)]
+    shortcuts: BTreeMap<String, RectangleShortcut>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RectangleShortcut {
+    key_code: Option<u16>,
+    modifier_flags: Option<u64>,
+}
+
+/// Map a macOS virtual key code (`kVK_*`) to a key name understood by `Shortcut::from_str`
+pub fn mac_key_name(key_code: u16) -> Option<&'static str> {
+    Some(match key_code {
+        0x00 => "A",
+        0x01 => "S",
+        0x02 => "D",
+        0x03 => "F",
+        0x04 => "H",
+        0x05 => "G",
+        0x06 => "Z",
+        0x07 => "X",
+        0x08 => "C",
+        0x09 => "V",
+        0x0B => "B",
+        0x0C => "Q",
+        0x0D => "W",
+        0x0E => "E",
+        0x0F => "R",
+        0x10 => "Y",
+        0x11 => "T",
+        0x12 => "1",
+        0x13 => "2",
+        0x14 => "3",
+        0x15 => "4",
+        0x16 => "6",
+        0x17 => "5",
+        0x18 => "Equal",
+        0x19 => "9",
+        0x1A => "7",
+        0x1B => "Minus",
+        0x1C => "8",
+        0x1D => "0",
+        0x1E => "BracketRight",
+        0x1F => "O",
+        0x20 => "U",
+        0x21 => "BracketLeft",
+        0x22 => "I",
+        0x23 => "P",
+        0x24 => "Enter",
+        0x25 => "L",
+        0x26 => "J",
+        0x27 => "Quote",
+        0x28 => "K",
+        0x29 => "Semicolon",
+        0x2A => "Backslash",
+        0x2B => "Comma",
+        0x2C => "Slash",
+        0x2D => "N",
+        0x2E => "M",
+        0x2F => "Period",
+        0x30 => "Tab",
+        0x31 => "Space",
+        0x32 => "Backquote",
+        0x33 => "Backspace",
+        0x35 => "Escape",
+        0x41 => "NumpadDecimal",
+        0x43 => "NumpadMultiply",
+        0x45 => "NumpadAdd",
+        0x4B => "NumpadDivide",
+        0x4C => "NumpadEnter",
+        0x4E => "NumpadSubtract",
+        0x51 => "NumpadEqual",
+        0x52 => "Numpad0",
+        0x53 => "Numpad1",
+        0x54 => "Numpad2",
+        0x55 => "Numpad3",
+        0x56 => "Numpad4",
+        0x57 => "Numpad5",
+        0x58 => "Numpad6",
+        0x59 => "Numpad7",
+        0x5B => "Numpad8",
+        0x5C => "Numpad9",
+        0x40 => "F17",
+        0x4F => "F18",
+        0x50 => "F19",
+        0x5A => "F20",
+        0x60 => "F5",
+        0x61 => "F6",
+        0x62 => "F7",
+        0x63 => "F3",
+        0x64 => "F8",
+        0x65 => "F9",
+        0x67 => "F11",
+        0x69 => "F13",
+        0x6A => "F16",
+        0x6B => "F14",
+        0x6D => "F10",
+        0x6F => "F12",
+        0x71 => "F15",
+        0x73 => "Home",
+        0x74 => "PageUp",
+        0x75 => "Delete",
+        0x76 => "F4",
+        0x77 => "End",
+        0x78 => "F2",
+        0x79 => "PageDown",
+        0x7A => "F1",
+        0x7B => "ArrowLeft",
+        0x7C => "ArrowRight",
+        0x7D => "ArrowDown",
+        0x7E => "ArrowUp",
+        _ => return None,
+    })
+}
+
+/// Rectangle's action names are the camelCase forms of our kebab-case `LayoutAction` names
+fn action_for(name: &str) -> Result<LayoutAction, String> {
+    let mut kebab = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            kebab.push('-');
+        }
+        kebab.push(c.to_ascii_lowercase());
+    }
+
+    kebab
+        .parse()
+        .map_err(|_| format!("Unsupported action {}", name))
+}
+
+fn binding_for(name: &str, shortcut: &RectangleShortcut) -> Result<(String, LayoutAction), String> {
+    let action = action_for(name)?;
+
+    let (Some(key_code), Some(flags)) = (shortcut.key_code, shortcut.modifier_flags) else {
+        return Err("No shortcut assigned".to_string());
+    };
+    let key = mac_key_name(key_code).ok_or_else(|| format!("Unsupported key code {}", key_code))?;
+
+    let mut modifiers = Vec::new();
+    if flags & SHIFT_FLAG != 0 {
+        modifiers.push("shift");
+    }
+    if flags & CONTROL_FLAG != 0 {
+        modifiers.push("control");
+    }
+    if flags & OPTION_FLAG != 0 {
+        modifiers.push("alt");
+    }
+    if flags & COMMAND_FLAG != 0 {
+        modifiers.push("super");
+    }
+
+    Ok((shortcut_string(&modifiers, key)?, action))
+}
+
+/// Read the shortcuts from a Rectangle config export (`RectangleConfig.json`)
+pub fn parse_config(json: &str) -> Result<Vec<ForeignBinding>, String> {
+    let config: RectangleConfig = serde_json::from_str(json)
+        .map_err(|e| format!("Failed to parse Rectangle config: {}", e))?;
+
+    Ok(config
+        .shortcuts
+        .iter()
+        .map(|(name, shortcut)| ForeignBinding {
+            name: name.clone(),
+            binding: binding_for(name, shortcut).map(|(keys, action)| (keys, vec![action])),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn import_rectangle_shortcuts(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<HotkeyImportReport, CrossZonesError> {
+    let json = std::fs::read_to_string(&path).map_err(|e| CrossZonesError::file(&path, e))?;
+    let bindings = parse_config(&json).map_err(CrossZonesError::Validation)?;
+    register_foreign_bindings(&app, bindings)
+}
@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+use crate::error::CrossZonesError;
+use crate::import::{register_foreign_bindings, shortcut_string, ForeignBinding, HotkeyImportReport};
+use crate::snapping::action::LayoutAction;
+
+#[derive(Debug, Deserialize)]
+struct SpectacleShortcut {
+    shortcut_name: String,
+    shortcut_key_binding: Option<String>,
+}
+
+fn action_for(name: &str) -> Result<LayoutAction, String> {
+    Ok(match name {
+        "MoveToCenter" => LayoutAction::Center,
+        "MoveToFullscreen" => LayoutAction::Maximize,
+        "MoveToLeftHalf" => LayoutAction::LeftHalf,
+        "MoveToRightHalf" => LayoutAction::RightHalf,
+        "MoveToTopHalf" => LayoutAction::TopHalf,
+        "MoveToBottomHalf" => LayoutAction::BottomHalf,
+        "MoveToUpperLeft" => LayoutAction::TopLeft,
+        "MoveToLowerLeft" => LayoutAction::BottomLeft,
+        "MoveToUpperRight" => LayoutAction::TopRight,
+        "MoveToLowerRight" => LayoutAction::BottomRight,
+        "MoveToNextDisplay" => LayoutAction::NextDisplay,
+        "MoveToPreviousDisplay" => LayoutAction::PreviousDisplay,
+        "MakeLarger" => LayoutAction::Larger,
+        "MakeSmaller" => LayoutAction::Smaller,
+        "UndoLastMove" => LayoutAction::Restore,
+        _ => return Err(format!("Unsupported action {}", name)),
+    })
+}
+
+/// Spectacle bindings look like `alt+cmd+left`
+fn binding_for(shortcut: &SpectacleShortcut) -> Result<(String, LayoutAction), String> {
+    let action = action_for(&shortcut.shortcut_name)?;
+
+    let binding = shortcut
+        .shortcut_key_binding
+        .as_deref()
+        .filter(|binding| !binding.is_empty())
+        .ok_or("No shortcut assigned")?;
+
+    let mut modifiers = Vec::new();
+    let mut key = None;
+    for token in binding.split('+') {
+        match token.to_lowercase().as_str() {
+            "shift" => modifiers.push("shift"),
+            "ctrl" | "control" => modifiers.push("control"),
+            "alt" | "option" => modifiers.push("alt"),
+            "cmd" | "command" => modifiers.push("super"),
+            _ if key.is_none() => key = Some(token),
+            _ => return Err(format!("Unsupported shortcut {}", binding)),
+        }
+    }
+
+    let key = key.ok_or_else(|| format!("Shortcut {} has no key", binding))?;
+    Ok((shortcut_string(&modifiers, key)?, action))
+}
+
+/// Read the shortcuts from Spectacle's `Shortcuts.json`
+pub fn parse_shortcuts(json: &str) -> Result<Vec<ForeignBinding>, String> {
+    let shortcuts: Vec<SpectacleShortcut> = serde_json::from_str(json)
+        .map_err(|e| format!("Failed to parse Spectacle shortcuts: {}", e))?;
+
+    Ok(shortcuts
+        .iter()
+        .map(|shortcut| ForeignBinding {
+            name: shortcut.shortcut_name.clone(),
+            binding: binding_for(shortcut).map(|(keys, action)| (keys, vec![action])),
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub fn import_spectacle_shortcuts(
+    app: tauri::AppHandle,
+    path: String,
+) -> Result<HotkeyImportReport, CrossZonesError> {
+    let json = std::fs::read_to_string(&path).map_err(|e| CrossZonesError::file(&path, e))?;
+    let bindings = parse_shortcuts(&json).map_err(CrossZonesError::Validation)?;
+    register_foreign_bindings(&app, bindings)
+}
@@ -1,12 +1,23 @@
 mod autostart;
 mod drag_drop;
+mod error;
 mod hotkeys;
+mod import;
 mod snapping;
 mod store;
 mod tray;
 mod window;
+mod window_rules;
 mod zones;
 
+/// Internals used by the benchmarks in `benches/`
+#[doc(hidden)]
+pub mod bench {
+    pub use crate::store::cache::Cached;
+    pub use crate::store::settings::Settings;
+    pub use crate::store::zone_layouts::ZoneLayout;
+}
+
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_notification::init())
@@ -19,21 +30,58 @@ pub fn run() {
         .plugin(tauri_plugin_user_input::init())
         .invoke_handler(tauri::generate_handler![
             hotkeys::register_hotkey_action,
-            hotkeys::unregister_hotkey_action,
+            hotkeys::register_hotkey_macro,
+            hotkeys::unregister_hotkey,
             hotkeys::get_all_hotkeys,
+            hotkeys::get_hotkey_report,
             hotkeys::clear_all_hotkeys,
+            hotkeys::get_hotkey_options,
+            hotkeys::set_hotkey_options,
+            hotkeys::chords::get_all_chords,
+            hotkeys::chords::register_chord,
+            hotkeys::chords::unregister_chord,
+            hotkeys::profiles::get_hotkey_profiles,
+            hotkeys::profiles::get_active_hotkey_profile_id,
+            hotkeys::profiles::create_hotkey_profile,
+            hotkeys::profiles::delete_hotkey_profile,
+            hotkeys::profiles::activate_hotkey_profile,
+            hotkeys::presets::get_hotkey_presets,
+            hotkeys::presets::check_hotkey_preset,
+            hotkeys::presets::apply_hotkey_preset,
+            hotkeys::recorder::record_next_shortcut,
+            hotkeys::recorder::cancel_shortcut_recording,
+            import::fancyzones::import_fancyzones_layouts,
+            import::rectangle::import_rectangle_shortcuts,
+            import::spectacle::import_spectacle_shortcuts,
             store::zone_layouts::get_all_zone_layouts,
             store::zone_layouts::save_zone_layout,
             store::zone_layouts::delete_zone_layout,
             store::zone_layouts::get_zone_layout,
             store::zone_layouts::get_active_zone_layout_id,
             store::zone_layouts::set_active_zone_layout_id,
+            store::bundle::export_config_bundle,
+            store::bundle::import_config_bundle,
+            store::config_file::reload_config,
+            store::config_file::get_config_file_path,
+            store::settings::get_settings,
+            store::settings::update_settings,
+            store::settings::reset_settings,
+            window_rules::get_window_rules,
+            window_rules::save_window_rules,
+            window_rules::get_window_exclusions,
+            window_rules::save_window_exclusions,
+            window_rules::reset_window_exclusions,
             zones::zone_layout_editor::get_all_screens,
             zones::zone_layout_editor::create_zone_editor_windows,
             zones::zone_layout_editor::destroy_all_editor_windows,
             zones::zone_layout_editor::close_editor_windows,
             zones::zone_layout_editor::store_editor_zones,
             zones::zone_layout_editor::get_editor_zones,
+            zones::split_tree::split_layout_zone,
+            zones::split_tree::merge_layout_zones,
+            zones::split_tree::move_layout_divider,
+            zones::templates::generate_zone_layout,
+            zones::validation::validate_zone_layout,
         ])
         .setup(move |app| {
             #[cfg(target_os = "macos")]
@@ -41,9 +89,14 @@ pub fn run() {
                 app.set_activation_policy(tauri::ActivationPolicy::Accessory);
             }
 
+            store::migrations::run_migrations(app.handle());
+            store::cache::setup(app.handle());
             hotkeys::setup(app.handle());
+            store::config_file::setup(app.handle());
             tray::setup_tray(app.handle());
             autostart::setup_autostart(app.handle());
+            zones::validation::validate_stored_layouts(app.handle());
+            window_rules::setup(app.handle());
 
             // Start drag detection
             if let Err(e) = drag_drop::start_drag_detection(app.handle()) {
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use crate::snapping::action::LayoutAction;
 use crate::snapping::window_rect::WindowRect;
 use crate::snapping::window_state::{get_window_state, insert_window_state, WindowState};
-use crate::store::zone_layouts;
+use crate::store::zone_layouts::{self, Zone};
 
 /// Represents screen dimensions
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -15,6 +15,9 @@ pub struct ScreenDimensions {
     pub height: i32,
 }
 
+/// The smallest width or height `ResizeBy` shrinks a window to
+const MIN_WINDOW_SIZE: i32 = 100;
+
 /// Ensures a window stays within screen boundaries
 fn constrain_to_screen(rect: WindowRect, screen: ScreenDimensions) -> WindowRect {
     WindowRect {
@@ -53,6 +56,54 @@ pub fn calculate_window_rect(
     current_state.window_rect = current;
     insert_window_state(window_id, current_state);
 
+    target_rect(action, screen, current, previous_state, app_handle)
+}
+
+/// Calculate where an action would put the window without recording any window state,
+/// e.g. to preview it
+pub fn preview_window_rect(
+    window_id: &str,
+    action: LayoutAction,
+    screen: ScreenDimensions,
+    current_rect: WindowRect,
+    app_handle: Option<&tauri::AppHandle>,
+) -> WindowRect {
+    let previous_state =
+        get_window_state(window_id).or_else(|| Some(WindowState::new(window_id, current_rect)));
+
+    target_rect(action, screen, current_rect, previous_state, app_handle)
+}
+
+/// Where `zone` of any layout puts a window on `screen`, in screen coordinates
+pub fn zone_rect(zone: &Zone, screen: ScreenDimensions) -> WindowRect {
+    // Zone coordinates are percentages of the screen
+    WindowRect {
+        x: screen.x + ((zone.x / 100.0) * screen.width as f64) as i32,
+        y: screen.y + ((zone.y / 100.0) * screen.height as f64) as i32,
+        width: ((zone.width / 100.0) * screen.width as f64) as i32,
+        height: ((zone.height / 100.0) * screen.height as f64) as i32,
+    }
+}
+
+/// The window centered on another screen, keeping its size where it fits
+pub fn fit_on_screen(current: WindowRect, screen: ScreenDimensions) -> WindowRect {
+    let width = current.width.min(screen.width);
+    let height = current.height.min(screen.height);
+    WindowRect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn target_rect(
+    action: LayoutAction,
+    screen: ScreenDimensions,
+    current: WindowRect,
+    previous_state: Option<WindowState>,
+    app_handle: Option<&tauri::AppHandle>,
+) -> WindowRect {
     let result = match action {
         LayoutAction::LeftHalf => WindowRect {
             x: 0,
@@ -303,8 +354,21 @@ pub fn calculate_window_rect(
             width: screen.width / 3,
             height: screen.height,
         },
+        // Relative to the screen, like the other actions, since the screen offset is added below
+        LayoutAction::MoveBy { dx, dy } => WindowRect {
+            x: current.x - screen.x + dx,
+            y: current.y - screen.y + dy,
+            width: current.width,
+            height: current.height,
+        },
+        LayoutAction::ResizeBy { width, height } => WindowRect {
+            x: current.x - screen.x,
+            y: current.y - screen.y,
+            width: (current.width + width).max(MIN_WINDOW_SIZE),
+            height: (current.height + height).max(MIN_WINDOW_SIZE),
+        },
         // Zone-based actions
-        LayoutAction::ApplyZone(zone_number) => {
+        LayoutAction::ApplyZone { zone_number } => {
             // Try to load active zone layout and apply zone
             if let Some(app) = app_handle {
                 if let Ok(Some(active_layout_id)) =
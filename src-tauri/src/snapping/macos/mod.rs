@@ -4,43 +4,79 @@ use accessibility::AXUIElementAttributes;
 use core_graphics_types::geometry::{CGPoint, CGSize};
 
 use super::action::LayoutAction;
-use super::common::calculate_window_rect;
+use super::common::{calculate_window_rect, preview_window_rect};
 use super::common::ScreenDimensions;
 use super::window_rect::WindowRect;
-use crate::window::macos::{get_frontmost_window, get_screen_dimensions_for_window};
+use crate::error::CrossZonesError;
+use crate::store::hotkey_options::WindowTarget;
+use crate::window::macos::{
+    get_frontmost_window, get_screen_dimensions_for_window, get_window_on_cursor_display,
+    get_window_under_cursor,
+};
+use crate::window_rules::macos::is_excluded_window;
+
+/// The window state is kept under a single id until windows can be told apart
+const WINDOW_STATE_ID: &str = "blabla";
 
 // Function to snap a window according to the specified layout action
 pub fn snap_window(
     action: LayoutAction,
     app_handle: Option<tauri::AppHandle>,
-) -> Result<(), String> {
-    // Get the frontmost window
-    let window = get_frontmost_window()?;
+) -> Result<(), CrossZonesError> {
+    check_accessibility_permission()?;
 
-    // Get the current window position and size
-    let current_rect = get_window_rect(&window)?;
+    // Get the frontmost window
+    let window = get_frontmost_window().map_err(CrossZonesError::Platform)?;
 
-    // Get screen dimensions
-    let screen_dimensions = get_screen_dimensions_for_window(&window)?;
+    snap_window_with_element(action, app_handle, &window)
+}
 
-    let identifier = "blabla";
-    let identifier_string = identifier.to_string();
+// Function to snap the window a hotkey targets
+pub fn snap_window_target(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+    target: WindowTarget,
+) -> Result<(), CrossZonesError> {
+    check_accessibility_permission()?;
+
+    let window = match target {
+        WindowTarget::Focused => get_frontmost_window(),
+        WindowTarget::UnderCursor => get_window_under_cursor(),
+        WindowTarget::CursorDisplay => get_window_on_cursor_display(),
+    }
+    .map_err(CrossZonesError::Platform)?;
+
+    snap_window_with_element(action, app_handle, &window)
+}
 
-    // Calculate new position and size based on the action
-    let new_rect = calculate_window_rect(
-        &identifier_string,
-        action,
-        screen_dimensions,
-        Some(current_rect),
-        app_handle.as_ref(),
-    );
+/// A window to act on later, e.g. the one window mode started with
+pub type CapturedWindow = AXUIElement;
 
-    // Apply the new position and size
-    set_window_rect(&window, new_rect)?;
+// The frontmost window, to act on later with `snap_captured_window`
+pub fn focused_window() -> Option<CapturedWindow> {
+    get_frontmost_window().ok()
+}
 
-    Ok(())
+// Function to snap a window captured earlier, whichever window is frontmost now
+pub fn snap_captured_window(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+    window: &CapturedWindow,
+) -> Result<(), CrossZonesError> {
+    check_accessibility_permission()?;
+    snap_window_with_element(action, app_handle, window)
 }
 
+/// Moving other apps' windows needs accessibility access
+fn check_accessibility_permission() -> Result<(), CrossZonesError> {
+    if unsafe { accessibility_sys::AXIsProcessTrusted() } {
+        Ok(())
+    } else {
+        Err(CrossZonesError::Permission(
+            "CrossZones needs accessibility access to move windows".to_string(),
+        ))
+    }
+}
 
 // Helper function to get window rectangle
 fn get_window_rect(window: &AXUIElement) -> Result<WindowRect, String> {
@@ -86,29 +122,72 @@ pub fn snap_window_with_element(
     action: LayoutAction,
     app_handle: Option<tauri::AppHandle>,
     window: &AXUIElement,
-) -> Result<(), String> {
+) -> Result<(), CrossZonesError> {
+    // Our own windows, the Dock and excluded apps stay where they are
+    if app_handle
+        .as_ref()
+        .is_some_and(|app| is_excluded_window(app, window))
+    {
+        return Ok(());
+    }
+
+    place_window_with_element(window, |current_rect, screen_dimensions| {
+        // Calculate new position and size based on the action
+        calculate_window_rect(
+            WINDOW_STATE_ID,
+            action,
+            screen_dimensions,
+            Some(current_rect),
+            app_handle.as_ref(),
+        )
+    })
+}
+
+// Public function to move a specific window to a rect of our own, such as a zone of a
+// layout that isn't active. This is used by window rules.
+pub fn place_window_with_element(
+    window: &AXUIElement,
+    rect_for: impl FnOnce(WindowRect, ScreenDimensions) -> WindowRect,
+) -> Result<(), CrossZonesError> {
+    check_accessibility_permission()?;
+
     // Get the current window position and size
-    let current_rect = get_window_rect(window)?;
+    let current_rect = get_window_rect(window).map_err(CrossZonesError::Platform)?;
 
     // Get screen dimensions
-    let screen_dimensions = get_screen_dimensions_for_window(window)?;
+    let screen_dimensions =
+        get_screen_dimensions_for_window(window).map_err(CrossZonesError::Platform)?;
+
+    let new_rect = rect_for(current_rect, screen_dimensions);
+
+    // Apply the new position and size
+    set_window_rect(window, new_rect).map_err(CrossZonesError::Platform)?;
 
-    let identifier = "blabla";
-    let identifier_string = identifier.to_string();
+    Ok(())
+}
+
+// Where an action would put the frontmost window, and the screen it is on
+// Nothing is moved, this is used to preview hotkeys while they are held
+pub fn preview_window(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+) -> Result<(WindowRect, ScreenDimensions), CrossZonesError> {
+    check_accessibility_permission()?;
 
-    // Calculate new position and size based on the action
-    let new_rect = calculate_window_rect(
-        &identifier_string,
+    let window = get_frontmost_window().map_err(CrossZonesError::Platform)?;
+    let current_rect = get_window_rect(&window).map_err(CrossZonesError::Platform)?;
+    let screen_dimensions =
+        get_screen_dimensions_for_window(&window).map_err(CrossZonesError::Platform)?;
+
+    let rect = preview_window_rect(
+        WINDOW_STATE_ID,
         action,
         screen_dimensions,
-        Some(current_rect),
+        current_rect,
         app_handle.as_ref(),
     );
 
-    // Apply the new position and size
-    set_window_rect(window, new_rect)?;
-
-    Ok(())
+    Ok((rect, screen_dimensions))
 }
 
 // Helper function to get all visible windows
@@ -13,10 +13,16 @@ pub mod macos;
 pub mod linux;
 
 #[cfg(target_os = "windows")]
-pub use windows::snap_window;
+pub use windows::{
+    focused_window, preview_window, snap_captured_window, snap_window, snap_window_target,
+    CapturedWindow,
+};
 
 #[cfg(target_os = "macos")]
-pub use macos::snap_window;
+pub use macos::{
+    focused_window, preview_window, snap_captured_window, snap_window, snap_window_target,
+    CapturedWindow,
+};
 
 #[cfg(target_os = "linux")]
 pub use linux::snap_window;
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 use std::sync::Mutex;
 
@@ -23,11 +23,31 @@ impl WindowState {
 static WINDOW_STATE_STORE: LazyLock<Mutex<HashMap<String, WindowState>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+/// Windows whose state is kept while states are held, `None` while they aren't
+static HELD_WINDOWS: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+/// Keep the first state each window gets from now on, so `Restore` goes back to the
+/// frame from before a series of actions instead of the last one
+pub fn hold_window_states() {
+    *HELD_WINDOWS.lock().unwrap() = Some(HashSet::new());
+}
+
+/// Go back to recording a state for every action
+pub fn release_window_states() {
+    *HELD_WINDOWS.lock().unwrap() = None;
+}
+
 pub fn get_window_state(window_id: &str) -> Option<WindowState> {
     WINDOW_STATE_STORE.lock().unwrap().get(window_id).cloned()
 }
 
 pub fn insert_window_state(window_id: &str, window_state: WindowState) {
+    if let Some(held) = HELD_WINDOWS.lock().unwrap().as_mut() {
+        if !held.insert(window_id.to_string()) {
+            return;
+        }
+    }
+
     WINDOW_STATE_STORE
         .lock()
         .unwrap()
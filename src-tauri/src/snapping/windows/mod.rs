@@ -1,41 +1,80 @@
 use windows::{
     core::BOOL,
     Win32::{
-        Foundation::{HWND, LPARAM, RECT, TRUE},
+        Foundation::{HWND, LPARAM, POINT, RECT, TRUE},
         Graphics::{
-            Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS},
+            Dwm::{DwmGetWindowAttribute, DWMWA_CLOAKED, DWMWA_EXTENDED_FRAME_BOUNDS},
             Gdi::{
-                GetDC, GetDeviceCaps, GetMonitorInfoW, MonitorFromWindow, LOGPIXELSY, MONITORINFO,
-                MONITOR_DEFAULTTONEAREST,
+                GetDC, GetDeviceCaps, GetMonitorInfoW, MonitorFromPoint, MonitorFromWindow,
+                LOGPIXELSY, MONITORINFO, MONITOR_DEFAULTTONEAREST,
             },
         },
         System::Threading::GetCurrentProcessId,
         UI::{
             HiDpi::GetDpiForWindow,
             WindowsAndMessaging::{
-                EnumWindows, GetForegroundWindow, GetWindowRect, GetWindowThreadProcessId,
-                IsWindowVisible, IsZoomed, SetWindowPos, ShowWindow, SWP_NOACTIVATE, SWP_NOZORDER,
-                SW_RESTORE,
+                EnumWindows, GetAncestor, GetCursorPos, GetForegroundWindow, GetWindowLongW,
+                GetWindowRect, GetWindowTextLengthW, GetWindowThreadProcessId, IsIconic,
+                IsWindowVisible, IsZoomed, SetWindowPos, ShowWindow, WindowFromPoint, GA_ROOT,
+                GWL_EXSTYLE, SWP_NOACTIVATE, SWP_NOZORDER, SW_RESTORE, WS_EX_TOOLWINDOW,
             },
         },
     },
 };
 
 use super::action::LayoutAction;
-use super::common::{calculate_window_rect, ScreenDimensions};
+use super::common::{calculate_window_rect, preview_window_rect, ScreenDimensions};
+use crate::error::CrossZonesError;
 use super::window_rect::WindowRect;
+use crate::store::hotkey_options::WindowTarget;
+use crate::window::windows::get_screen_dimensions_for_window;
+use crate::window_rules::windows::is_excluded_window;
 
 // Function to snap a window according to the specified layout action
 // Uses the foreground window by default
 pub fn snap_window(
     action: LayoutAction,
     app_handle: Option<tauri::AppHandle>,
-) -> Result<(), String> {
+) -> Result<(), CrossZonesError> {
     let fg = unsafe { GetForegroundWindow() };
     if fg.0 == std::ptr::null_mut() {
-        return Err("Failed to get foreground window".to_string());
+        return Err(CrossZonesError::NotFound("Foreground window".to_string()));
+    }
+    snap_window_internal(action, app_handle, fg).map_err(CrossZonesError::Platform)
+}
+
+// Function to snap the window a hotkey targets
+pub fn snap_window_target(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+    target: WindowTarget,
+) -> Result<(), CrossZonesError> {
+    let hwnd = match target {
+        WindowTarget::Focused => return snap_window(action, app_handle),
+        WindowTarget::UnderCursor => window_under_cursor(),
+        WindowTarget::CursorDisplay => window_on_cursor_display(),
     }
-    snap_window_internal(action, app_handle, fg)
+    .ok_or_else(|| CrossZonesError::NotFound("Target window".to_string()))?;
+
+    snap_window_internal(action, app_handle, hwnd).map_err(CrossZonesError::Platform)
+}
+
+/// A window to act on later, e.g. the one window mode started with
+pub type CapturedWindow = HWND;
+
+// The foreground window, to act on later with `snap_captured_window`
+pub fn focused_window() -> Option<CapturedWindow> {
+    let fg = unsafe { GetForegroundWindow() };
+    (!fg.0.is_null()).then_some(fg)
+}
+
+// Function to snap a window captured earlier, whichever window has the focus now
+pub fn snap_captured_window(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+    window: &CapturedWindow,
+) -> Result<(), CrossZonesError> {
+    snap_window_internal(action, app_handle, *window).map_err(CrossZonesError::Platform)
 }
 
 // Internal function to snap a specific window by handle, or foreground window if None
@@ -43,6 +82,34 @@ fn snap_window_internal(
     action: LayoutAction,
     app_handle: Option<tauri::AppHandle>,
     hwnd: HWND,
+) -> Result<(), String> {
+    // Our own windows, the desktop and excluded apps stay where they are
+    if app_handle
+        .as_ref()
+        .is_some_and(|app| is_excluded_window(app, hwnd))
+    {
+        return Ok(());
+    }
+
+    let window_id = format!("{:?}", hwnd.0);
+
+    place_window_internal(hwnd, |current_rect, screen_dimensions| {
+        // Calculate new position and size based on the action
+        calculate_window_rect(
+            &window_id,
+            action,
+            screen_dimensions,
+            Some(current_rect),
+            app_handle.as_ref(),
+        )
+    })
+}
+
+// Move a window to the rect computed from its frame and the work area of its monitor,
+// without the invisible borders
+fn place_window_internal(
+    hwnd: HWND,
+    rect_for: impl FnOnce(WindowRect, ScreenDimensions) -> WindowRect,
 ) -> Result<(), String> {
     // Check if the window is maximized and restore it first
     // On Windows, you cannot resize a maximized window directly with SetWindowPos
@@ -118,16 +185,7 @@ fn snap_window_internal(
         height: rect.bottom - rect.top - height_addition,
     };
 
-    let window_id = format!("{:?}", hwnd.0);
-
-    // Calculate new position and size based on the action
-    let mut new_rect = calculate_window_rect(
-        &window_id,
-        action,
-        screen_dimensions,
-        Some(current_rect),
-        app_handle.as_ref(),
-    );
+    let mut new_rect = rect_for(current_rect, screen_dimensions);
 
     // Add the effect of the invisible borders to get the correct position and size
     new_rect = WindowRect {
@@ -170,8 +228,111 @@ pub fn snap_window_with_handle(
     action: LayoutAction,
     app_handle: Option<tauri::AppHandle>,
     hwnd: HWND,
-) -> Result<(), String> {
-    snap_window_internal(action, app_handle, hwnd)
+) -> Result<(), CrossZonesError> {
+    snap_window_internal(action, app_handle, hwnd).map_err(CrossZonesError::Platform)
+}
+
+// Public function to move a specific window by handle to a rect of our own, such as a
+// zone of a layout that isn't active. This is used by window rules.
+pub fn place_window_with_handle(
+    hwnd: HWND,
+    rect_for: impl FnOnce(WindowRect, ScreenDimensions) -> WindowRect,
+) -> Result<(), CrossZonesError> {
+    place_window_internal(hwnd, rect_for).map_err(CrossZonesError::Platform)
+}
+
+// Where an action would put the foreground window, and the work area of its monitor
+// Nothing is moved, this is used to preview hotkeys while they are held
+pub fn preview_window(
+    action: LayoutAction,
+    app_handle: Option<tauri::AppHandle>,
+) -> Result<(WindowRect, ScreenDimensions), CrossZonesError> {
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.0 == std::ptr::null_mut() {
+        return Err(CrossZonesError::NotFound("Foreground window".to_string()));
+    }
+
+    // The frame without the invisible borders, as snapping sees it
+    let mut frame_rect = RECT::default();
+    let result = unsafe {
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_EXTENDED_FRAME_BOUNDS,
+            &mut frame_rect as *mut _ as *mut std::ffi::c_void,
+            std::mem::size_of::<RECT>() as u32,
+        )
+    };
+    if result.is_err() {
+        return Err(CrossZonesError::Platform(
+            "Failed to get window frame".to_string(),
+        ));
+    }
+
+    let screen_dimensions =
+        get_screen_dimensions_for_window(hwnd).map_err(CrossZonesError::Platform)?;
+
+    let current_rect = WindowRect {
+        x: frame_rect.left,
+        y: frame_rect.top,
+        width: frame_rect.right - frame_rect.left,
+        height: frame_rect.bottom - frame_rect.top,
+    };
+
+    let window_id = format!("{:?}", hwnd.0);
+
+    let rect = preview_window_rect(
+        &window_id,
+        action,
+        screen_dimensions,
+        current_rect,
+        app_handle.as_ref(),
+    );
+
+    Ok((rect, screen_dimensions))
+}
+
+fn cursor_position() -> Option<POINT> {
+    let mut point = POINT::default();
+    unsafe { GetCursorPos(&mut point) }.ok()?;
+    Some(point)
+}
+
+// The top-level window under the mouse cursor, rather than the control in it
+fn window_under_cursor() -> Option<HWND> {
+    let point = cursor_position()?;
+    let hwnd = unsafe { GetAncestor(WindowFromPoint(point), GA_ROOT) };
+    (!hwnd.0.is_null()).then_some(hwnd)
+}
+
+// Windows that are hidden by the shell even though they count as visible, like
+// suspended store apps
+fn is_cloaked(hwnd: HWND) -> bool {
+    let mut cloaked = 0u32;
+    let result = unsafe {
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_CLOAKED,
+            &mut cloaked as *mut _ as *mut std::ffi::c_void,
+            std::mem::size_of::<u32>() as u32,
+        )
+    };
+    result.is_ok() && cloaked != 0
+}
+
+// The topmost window on the monitor the mouse cursor is on, leaving out minimized,
+// untitled and tool windows such as the taskbar
+fn window_on_cursor_display() -> Option<HWND> {
+    let point = cursor_position()?;
+    let monitor = unsafe { MonitorFromPoint(point, MONITOR_DEFAULTTONEAREST) };
+
+    // EnumWindows lists windows from the top down
+    get_visible_windows().into_iter().find(|&hwnd| unsafe {
+        MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) == monitor
+            && !IsIconic(hwnd).as_bool()
+            && GetWindowTextLengthW(hwnd) > 0
+            && (GetWindowLongW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW.0) == 0
+            && !is_cloaked(hwnd)
+    })
 }
 
 // Helper function to get all visible windows
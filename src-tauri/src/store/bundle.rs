@@ -0,0 +1,274 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+use tauri_plugin_global_shortcut::Shortcut;
+use ts_rs::TS;
+
+use crate::error::{CrossZonesError, Result};
+use crate::hotkeys;
+use crate::import::{register_foreign_bindings, ForeignBinding, ImportIssue};
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkeys::{hotkey_entries, read_actions};
+use crate::store::settings::{Settings, SettingsStore};
+use crate::store::zone_layouts::{self, ZoneLayout};
+
+/// Version of the bundle format written by `export_config_bundle`
+pub const BUNDLE_VERSION: u32 = 2;
+
+/// Settings, hotkeys and zone layouts in a single file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32,
+    pub settings: Settings,
+    /// Shortcut → actions, in order
+    #[serde(deserialize_with = "deserialize_hotkeys")]
+    pub hotkeys: BTreeMap<String, Vec<LayoutAction>>,
+    pub zone_layouts: Vec<ZoneLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_layout_id: Option<String>,
+}
+
+/// Version 1 bundles have a single action per shortcut
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BundleHotkey {
+    Action(LayoutAction),
+    Macro(Vec<LayoutAction>),
+}
+
+fn deserialize_hotkeys<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<BTreeMap<String, Vec<LayoutAction>>, D::Error> {
+    let hotkeys = BTreeMap::<String, BundleHotkey>::deserialize(deserializer)?;
+    Ok(hotkeys
+        .into_iter()
+        .map(|(shortcut, hotkey)| match hotkey {
+            BundleHotkey::Action(action) => (shortcut, vec![action]),
+            BundleHotkey::Macro(actions) => (shortcut, actions),
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum BundleImportMode {
+    /// Keep the existing configuration and add the bundle to it
+    Merge,
+    /// Remove all existing hotkeys and layouts before importing
+    Replace,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BundleImportReport {
+    pub layouts: usize,
+    pub hotkeys: usize,
+    /// Layout ids that collided with existing layouts, as `[old, new]` pairs
+    pub remapped_layout_ids: Vec<(String, String)>,
+    pub issues: Vec<ImportIssue>,
+}
+
+/// Collect the current configuration into a bundle
+pub fn collect_bundle(app: &tauri::AppHandle) -> Result<ConfigBundle> {
+    let settings = SettingsStore::new(app)?.load_all()?;
+
+    let hotkeys = hotkey_entries(app)?
+        .into_iter()
+        .filter_map(|(shortcut, value)| Some((shortcut, read_actions(&value).ok()?)))
+        .collect();
+
+    Ok(ConfigBundle {
+        version: BUNDLE_VERSION,
+        settings,
+        hotkeys,
+        zone_layouts: zone_layouts::get_all_zone_layouts(app.clone())?,
+        active_layout_id: zone_layouts::get_active_zone_layout_id(app.clone())?,
+    })
+}
+
+/// Check that every layout and hotkey in the bundle can be saved on its own, without
+/// the current configuration
+fn check_bundle(bundle: &ConfigBundle) -> Result<()> {
+    let mut errors = Vec::new();
+
+    for layout in &bundle.zone_layouts {
+        if let Err(e) = zone_layouts::check_zone_layout(&mut layout.clone()) {
+            errors.push(format!("{}: {}", layout.name, e));
+        }
+    }
+
+    let mut shortcuts: Vec<Shortcut> = Vec::new();
+    for (key, actions) in &bundle.hotkeys {
+        let shortcut = match Shortcut::from_str(key) {
+            Ok(shortcut) => shortcut,
+            Err(e) => {
+                errors.push(format!("Invalid shortcut {}: {}", key, e));
+                continue;
+            }
+        };
+        if shortcuts.contains(&shortcut) {
+            errors.push(format!("{} is bound more than once", key));
+        } else if let Err(e) = hotkeys::check_hotkey(&shortcut, actions, &[]) {
+            errors.push(e.to_string());
+        }
+        shortcuts.push(shortcut);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CrossZonesError::Validation(format!(
+            "The bundle can't replace the current configuration: {}",
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Apply a bundle to the current configuration. In merge mode, layouts whose id is
+/// already taken get a new id, and hotkeys that activate them are updated to match.
+/// Replacing changes nothing unless every layout and hotkey in the bundle is valid and
+/// every shortcut can be registered.
+pub fn apply_bundle(
+    app: &tauri::AppHandle,
+    bundle: ConfigBundle,
+    mode: BundleImportMode,
+) -> Result<BundleImportReport> {
+    if bundle.version > BUNDLE_VERSION {
+        return Err(CrossZonesError::Validation(format!(
+            "Bundle version {} is newer than the supported version {}",
+            bundle.version, BUNDLE_VERSION
+        )));
+    }
+
+    // Replacing only clears the current configuration once the bundle is known to fit
+    if mode == BundleImportMode::Replace {
+        check_bundle(&bundle)?;
+    }
+
+    let mut issues = Vec::new();
+
+    let existing_ids: HashSet<String> = match mode {
+        BundleImportMode::Merge => zone_layouts::get_all_zone_layouts(app.clone())?
+            .into_iter()
+            .map(|layout| layout.id)
+            .collect(),
+        BundleImportMode::Replace => HashSet::new(),
+    };
+
+    let mut remapped: HashMap<String, String> = HashMap::new();
+    let mut layouts = bundle.zone_layouts;
+    for layout in &mut layouts {
+        if existing_ids.contains(&layout.id) {
+            let new_id = zone_layouts::generate_id("layout");
+            remapped.insert(layout.id.clone(), new_id.clone());
+            layout.id = new_id;
+        }
+    }
+
+    let bindings = bundle
+        .hotkeys
+        .into_iter()
+        .map(|(shortcut, actions)| {
+            let actions = actions
+                .into_iter()
+                .map(|action| match action {
+                    LayoutAction::ActivateLayout { layout_id } => LayoutAction::ActivateLayout {
+                        layout_id: remapped.get(&layout_id).cloned().unwrap_or(layout_id),
+                    },
+                    action => action,
+                })
+                .collect();
+            ForeignBinding {
+                name: shortcut.clone(),
+                binding: Ok((shortcut, actions)),
+            }
+        })
+        .collect();
+
+    // Hotkeys go first, as only registering them tells whether another app owns one of
+    // the shortcuts. A replace that can't register them all puts the previous hotkeys
+    // back before anything else is touched.
+    let previous = match mode {
+        BundleImportMode::Merge => None,
+        BundleImportMode::Replace => {
+            let previous = hotkeys::saved_hotkeys(app)?;
+            hotkeys::clear_all_hotkeys(app.clone())?;
+            Some(previous)
+        }
+    };
+    let hotkey_report = register_foreign_bindings(app, bindings)?;
+    if let Some(previous) = previous {
+        if !hotkey_report.issues.is_empty() {
+            hotkeys::clear_all_hotkeys(app.clone())?;
+            hotkeys::restore_hotkeys(app, previous);
+            let messages: Vec<_> = hotkey_report
+                .issues
+                .iter()
+                .map(|issue| issue.message.as_str())
+                .collect();
+            return Err(CrossZonesError::Conflict(format!(
+                "The bundle can't replace the current configuration: {}",
+                messages.join("; ")
+            )));
+        }
+    }
+    issues.extend(hotkey_report.issues);
+
+    SettingsStore::new(app)?.update(&bundle.settings)?;
+
+    if mode == BundleImportMode::Replace {
+        for layout in zone_layouts::get_all_zone_layouts(app.clone())? {
+            zone_layouts::delete_zone_layout(app.clone(), layout.id)?;
+        }
+    }
+
+    let mut imported_ids = HashSet::new();
+    for layout in layouts {
+        let name = layout.name.clone();
+        let id = layout.id.clone();
+        match zone_layouts::save_zone_layout(app.clone(), layout) {
+            Ok(()) => {
+                imported_ids.insert(id);
+            }
+            Err(e) => issues.push(ImportIssue::skipped(name, e)),
+        }
+    }
+
+    if let Some(id) = bundle.active_layout_id {
+        let id = remapped.get(&id).cloned().unwrap_or(id);
+        // Merging only activates the bundle's layout if nothing was active before
+        if imported_ids.contains(&id)
+            && (mode == BundleImportMode::Replace
+                || zone_layouts::get_active_zone_layout_id(app.clone())?.is_none())
+        {
+            zone_layouts::set_active_zone_layout_id(app.clone(), Some(id))?;
+        }
+    }
+
+    Ok(BundleImportReport {
+        layouts: imported_ids.len(),
+        hotkeys: hotkey_report.imported.len(),
+        remapped_layout_ids: remapped.into_iter().collect(),
+        issues,
+    })
+}
+
+#[tauri::command]
+pub fn export_config_bundle(app: tauri::AppHandle, path: String) -> Result<()> {
+    let bundle = collect_bundle(&app)?;
+    let json = serde_json::to_string_pretty(&bundle)?;
+    std::fs::write(&path, json).map_err(|e| CrossZonesError::file(&path, e))
+}
+
+#[tauri::command]
+pub fn import_config_bundle(
+    app: tauri::AppHandle,
+    path: String,
+    mode: BundleImportMode,
+) -> Result<BundleImportReport> {
+    let json = std::fs::read_to_string(&path).map_err(|e| CrossZonesError::file(&path, e))?;
+    let bundle: ConfigBundle = serde_json::from_str(&json)
+        .map_err(|e| CrossZonesError::Validation(format!("Failed to parse bundle: {}", e)))?;
+    apply_bundle(&app, bundle, mode)
+}
@@ -0,0 +1,129 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tauri::Listener;
+
+use crate::error::CrossZonesError;
+use crate::store::settings::{Settings, SettingsStore, SETTINGS_STORE_NAME};
+use crate::store::window_rules::{self, WINDOW_RULES_STORE_NAME};
+use crate::store::zone_layouts::{self, ZoneLayout, ZONE_LAYOUTS_STORE_NAME};
+use crate::window_rules::evaluator::{CompiledMatch, CompiledRule};
+
+/// A lazily loaded value that is dropped whenever its store changes.
+///
+/// Loads racing with an invalidation are not kept, so a reader can never
+/// bring back a value from before the latest write.
+pub struct Cached<T> {
+    value: RwLock<Option<Arc<T>>>,
+    generation: AtomicU64,
+}
+
+impl<T> Cached<T> {
+    pub const fn new() -> Self {
+        Self {
+            value: RwLock::new(None),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// The cached value, or the result of `load` if there is none
+    pub fn get_or_load(
+        &self,
+        load: impl FnOnce() -> Result<T, CrossZonesError>,
+    ) -> Result<Arc<T>, CrossZonesError> {
+        if let Some(value) = self.value.read().unwrap().as_ref() {
+            return Ok(value.clone());
+        }
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let value = Arc::new(load()?);
+
+        let mut slot = self.value.write().unwrap();
+        if self.generation.load(Ordering::Acquire) == generation {
+            *slot = Some(value.clone());
+        }
+        Ok(value)
+    }
+
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        *self.value.write().unwrap() = None;
+    }
+}
+
+impl<T> Default for Cached<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static SETTINGS: Cached<Settings> = Cached::new();
+static ACTIVE_LAYOUT: Cached<Option<ZoneLayout>> = Cached::new();
+static WINDOW_RULES: Cached<Vec<CompiledRule>> = Cached::new();
+static WINDOW_EXCLUSIONS: Cached<Vec<CompiledMatch>> = Cached::new();
+
+/// The part of the store plugin's `store://change` payload we need
+#[derive(Deserialize)]
+struct StoreChange {
+    path: PathBuf,
+}
+
+/// Current settings, without touching the store unless they changed since the last call
+pub fn settings(app: &tauri::AppHandle) -> Result<Arc<Settings>, CrossZonesError> {
+    SETTINGS.get_or_load(|| Ok(SettingsStore::new(app)?.load_all()?))
+}
+
+/// The active zone layout, without touching the store unless it changed since the last call
+pub fn active_layout(app: &tauri::AppHandle) -> Result<Arc<Option<ZoneLayout>>, CrossZonesError> {
+    ACTIVE_LAYOUT.get_or_load(|| match zone_layouts::get_active_zone_layout_id(app.clone())? {
+        Some(id) => zone_layouts::get_zone_layout(app.clone(), id),
+        None => Ok(None),
+    })
+}
+
+/// The window rules with their title patterns compiled, in the order they are tried
+pub fn window_rules(app: &tauri::AppHandle) -> Result<Arc<Vec<CompiledRule>>, CrossZonesError> {
+    WINDOW_RULES.get_or_load(|| {
+        Ok(window_rules::window_rules(app)?
+            .into_iter()
+            .map(CompiledRule::new)
+            .collect())
+    })
+}
+
+/// The window exclusions with their title patterns compiled
+pub fn window_exclusions(
+    app: &tauri::AppHandle,
+) -> Result<Arc<Vec<CompiledMatch>>, CrossZonesError> {
+    WINDOW_EXCLUSIONS.get_or_load(|| {
+        Ok(window_rules::window_exclusions(app)?
+            .iter()
+            .map(CompiledMatch::new)
+            .collect())
+    })
+}
+
+/// Drop cached values whenever their store is written, from Rust or the frontend
+pub fn setup(app_handle: &tauri::AppHandle) {
+    app_handle.listen("store://change", |event| {
+        let Ok(change) = serde_json::from_str::<StoreChange>(event.payload()) else {
+            return;
+        };
+        let Some(file_name) = change.path.file_name().and_then(|name| name.to_str()) else {
+            return;
+        };
+
+        // The store plugin holds its lock while emitting, so only invalidate here and
+        // let the next reader reload
+        match file_name {
+            SETTINGS_STORE_NAME => SETTINGS.invalidate(),
+            ZONE_LAYOUTS_STORE_NAME => ACTIVE_LAYOUT.invalidate(),
+            WINDOW_RULES_STORE_NAME => {
+                WINDOW_RULES.invalidate();
+                WINDOW_EXCLUSIONS.invalidate();
+            }
+            _ => {}
+        }
+    });
+}
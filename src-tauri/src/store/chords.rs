@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkeys::read_actions;
+use crate::store::migrations::SCHEMA_VERSION_KEY;
+
+pub const CHORDS_STORE_NAME: &str = "chords.json";
+
+/// Keys pressed one after the other following the leader key, and the actions they run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ChordBinding {
+    /// Key names as understood by `Shortcut::from_str`, e.g. `KeyH`
+    pub sequence: Vec<String>,
+    pub actions: Vec<LayoutAction>,
+}
+
+/// The store key for a sequence, e.g. `KeyH KeyL`
+pub fn sequence_key(sequence: &[String]) -> String {
+    sequence.join(" ")
+}
+
+/// Every stored chord. Fails if any entry can't be read.
+pub fn chord_bindings(app: &tauri::AppHandle) -> Result<Vec<ChordBinding>> {
+    let store = app.store(CHORDS_STORE_NAME)?;
+
+    store
+        .entries()
+        .into_iter()
+        .filter(|(key, _)| key != SCHEMA_VERSION_KEY)
+        .map(|(key, value)| match read_actions(&value) {
+            Ok(actions) => Ok(ChordBinding {
+                sequence: key.split(' ').map(str::to_string).collect(),
+                actions,
+            }),
+            Err(e) => Err(CrossZonesError::Store(format!("Chord {}: {}", key, e))),
+        })
+        .collect()
+}
@@ -0,0 +1,411 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use tauri::{Emitter, Manager};
+use tauri_plugin_global_shortcut::Shortcut;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::CrossZonesError;
+use crate::hotkeys;
+use crate::import::ImportIssue;
+use crate::snapping::action::LayoutAction;
+use crate::store::settings::{Settings, SettingsStore};
+use crate::store::zone_layouts::{self, Zone, ZoneLayout};
+use crate::zones::templates::ZoneLayoutTemplate;
+use crate::zones::validation::validate_layout;
+
+pub const CONFIG_FILE_NAME: &str = "crosszones.toml";
+
+/// Store for the bookkeeping of what the config file applied
+pub const CONFIG_FILE_STORE_NAME: &str = "config_file.json";
+
+/// How often the config file is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What the config file applied last time, so entries removed from the file can be
+/// removed again, also after a restart
+const APPLIED_KEY: &str = "applied";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct AppliedConfig {
+    shortcuts: HashSet<String>,
+    layout_ids: HashSet<String>,
+}
+
+/// Held while the file is applied, so the watcher and `reload_config` don't interleave
+static APPLYING: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    /// Only the settings present here are changed
+    #[serde(default)]
+    settings: toml::Table,
+    /// Shortcut → action name, a full action payload for actions with parameters,
+    /// or a list of either to run as a macro
+    #[serde(default)]
+    hotkeys: BTreeMap<String, HotkeyEntry>,
+    #[serde(default)]
+    layouts: Vec<LayoutEntry>,
+    active_layout: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum HotkeyEntry {
+    Step(HotkeyStep),
+    Macro(Vec<HotkeyStep>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum HotkeyStep {
+    Action(String),
+    Payload(toml::Table),
+}
+
+impl HotkeyStep {
+    fn parse(self) -> Result<LayoutAction, String> {
+        match self {
+            HotkeyStep::Action(action) => action.parse(),
+            HotkeyStep::Payload(payload) => toml::Value::Table(payload)
+                .try_into::<LayoutAction>()
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayoutEntry {
+    id: String,
+    name: Option<String>,
+    #[serde(default)]
+    zones: Vec<ZoneEntry>,
+    template: Option<ZoneLayoutTemplate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ZoneEntry {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    number: Option<u32>,
+}
+
+/// The config file after parsing and validation, ready to apply
+struct ParsedConfig {
+    settings: Settings,
+    hotkeys: Vec<(String, Vec<LayoutAction>)>,
+    layouts: Vec<ZoneLayout>,
+    active_layout: Option<String>,
+}
+
+pub fn config_file_path(app: &tauri::AppHandle) -> Result<PathBuf, CrossZonesError> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(CONFIG_FILE_NAME))
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to get config dir: {}", e)))
+}
+
+fn parse_settings(app: &tauri::AppHandle, table: toml::Table) -> Result<Settings, String> {
+    let current = SettingsStore::new(app)
+        .and_then(|store| store.load_all())
+        .map_err(|e| e.to_string())?;
+
+    let mut values = match serde_json::to_value(&current) {
+        Ok(serde_json::Value::Object(values)) => values,
+        _ => return Err("Failed to serialize settings".to_string()),
+    };
+
+    for (key, value) in table {
+        if !values.contains_key(&key) {
+            return Err(format!("Unknown setting \"{}\"", key));
+        }
+        let value = serde_json::to_value(value)
+            .map_err(|e| format!("Invalid value for setting \"{}\": {}", key, e))?;
+        values.insert(key, value);
+    }
+
+    let settings: Settings = serde_json::from_value(serde_json::Value::Object(values))
+        .map_err(|e| format!("Invalid settings: {}", e))?;
+    settings.validate().map_err(|e| e.to_string())?;
+    Ok(settings)
+}
+
+fn parse_layout(entry: LayoutEntry) -> Result<ZoneLayout, String> {
+    let mut zones = match (&entry.template, entry.zones.is_empty()) {
+        (Some(template), true) => template.zones()?,
+        (None, _) => entry
+            .zones
+            .iter()
+            .enumerate()
+            .map(|(index, zone)| Zone {
+                id: String::new(),
+                x: zone.x,
+                y: zone.y,
+                width: zone.width,
+                height: zone.height,
+                number: zone.number.unwrap_or(index as u32 + 1),
+            })
+            .collect(),
+        (Some(_), false) => {
+            return Err(format!(
+                "Layout \"{}\" has both a template and zones",
+                entry.id
+            ))
+        }
+    };
+
+    // Stable zone ids, so reloading an unchanged file doesn't change anything
+    for zone in &mut zones {
+        zone.id = format!("{}-zone-{}", entry.id, zone.number);
+    }
+
+    let layout = ZoneLayout {
+        name: entry.name.unwrap_or_else(|| entry.id.clone()),
+        id: entry.id,
+        zones,
+        screen_width: None,
+        screen_height: None,
+        split_tree: None,
+    };
+
+    let errors: Vec<String> = validate_layout(&layout)
+        .into_iter()
+        .filter(|issue| issue.is_error())
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
+        return Err(format!(
+            "Invalid layout \"{}\": {}",
+            layout.id,
+            errors.join("; ")
+        ));
+    }
+
+    Ok(layout)
+}
+
+/// Parse and validate the whole file up front, so a broken file changes nothing
+fn parse_config(app: &tauri::AppHandle, contents: &str) -> Result<ParsedConfig, String> {
+    let file: ConfigFile =
+        toml::from_str(contents).map_err(|e| format!("Failed to parse {}: {}", CONFIG_FILE_NAME, e))?;
+
+    let settings = parse_settings(app, file.settings)?;
+
+    let mut hotkeys = Vec::new();
+    for (shortcut, entry) in file.hotkeys {
+        let shortcut = Shortcut::from_str(&shortcut)
+            .map_err(|e| format!("Invalid shortcut \"{}\": {}", shortcut, e))?;
+        let steps = match entry {
+            HotkeyEntry::Step(step) => vec![step],
+            HotkeyEntry::Macro(steps) => steps,
+        };
+        if steps.is_empty() {
+            return Err(format!("No actions for {}", shortcut));
+        }
+        // Make sure every action is valid before anything is applied
+        let actions = steps
+            .into_iter()
+            .map(HotkeyStep::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Invalid action for {}: {}", shortcut, e))?;
+        hotkeys.push((shortcut.to_string(), actions));
+    }
+
+    let layouts = file
+        .layouts
+        .into_iter()
+        .map(parse_layout)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(active) = &file.active_layout {
+        if !layouts.iter().any(|layout| &layout.id == active) {
+            return Err(format!("Active layout \"{}\" is not declared", active));
+        }
+    }
+
+    Ok(ParsedConfig {
+        settings,
+        hotkeys,
+        layouts,
+        active_layout: file.active_layout,
+    })
+}
+
+fn load_applied(app: &tauri::AppHandle) -> Result<AppliedConfig, CrossZonesError> {
+    let store = app.store(CONFIG_FILE_STORE_NAME)?;
+    Ok(store
+        .get(APPLIED_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default())
+}
+
+fn save_applied(app: &tauri::AppHandle, applied: &AppliedConfig) -> Result<(), CrossZonesError> {
+    let store = app.store(CONFIG_FILE_STORE_NAME)?;
+    store.set(APPLIED_KEY, serde_json::to_value(applied)?);
+    store.save()?;
+    Ok(())
+}
+
+/// Apply the parsed file entry by entry. Entries that fail are reported and keep their
+/// current state, and stay tracked if an earlier version of the file applied them.
+fn apply_config(
+    app: &tauri::AppHandle,
+    config: ParsedConfig,
+) -> Result<Vec<ImportIssue>, CrossZonesError> {
+    let _applying = APPLYING.lock().unwrap_or_else(|e| e.into_inner());
+    let previous = load_applied(app)?;
+    let mut applied = AppliedConfig::default();
+    let mut issues = Vec::new();
+
+    SettingsStore::new(app)?.update(&config.settings)?;
+
+    // Drop hotkeys and layouts that were removed from the file since the last apply
+    let shortcuts: HashSet<&String> = config.hotkeys.iter().map(|(s, _)| s).collect();
+    for shortcut in &previous.shortcuts {
+        if shortcuts.contains(shortcut) {
+            continue;
+        }
+        if let Err(e) = hotkeys::remove_hotkey(app, shortcut) {
+            issues.push(ImportIssue::skipped(shortcut, e));
+            applied.shortcuts.insert(shortcut.clone());
+        }
+    }
+
+    let layout_ids: HashSet<&String> = config.layouts.iter().map(|l| &l.id).collect();
+    for id in &previous.layout_ids {
+        if layout_ids.contains(id) {
+            continue;
+        }
+        if let Err(e) = zone_layouts::delete_zone_layout(app.clone(), id.clone()) {
+            issues.push(ImportIssue::skipped(id, e));
+            applied.layout_ids.insert(id.clone());
+        }
+    }
+
+    for layout in config.layouts {
+        let id = layout.id.clone();
+        match zone_layouts::save_zone_layout(app.clone(), layout) {
+            Ok(()) => {
+                applied.layout_ids.insert(id);
+            }
+            Err(e) => {
+                if previous.layout_ids.contains(&id) {
+                    applied.layout_ids.insert(id.clone());
+                }
+                issues.push(ImportIssue::skipped(id, e));
+            }
+        }
+    }
+
+    for (shortcut, actions) in config.hotkeys {
+        // The file wins over whatever the shortcut was bound to before
+        match hotkeys::rebind_hotkey(app, &shortcut, &actions) {
+            Ok(()) => {
+                applied.shortcuts.insert(shortcut);
+            }
+            Err(e) => {
+                if previous.shortcuts.contains(&shortcut) {
+                    applied.shortcuts.insert(shortcut.clone());
+                }
+                issues.push(ImportIssue::skipped(shortcut, e));
+            }
+        }
+    }
+
+    if let Some(active) = config.active_layout {
+        if applied.layout_ids.contains(&active) {
+            zone_layouts::set_active_zone_layout_id(app.clone(), Some(active.clone()))?;
+            let _ = app.emit("active-layout-changed", active);
+        }
+    }
+
+    save_applied(app, &applied)?;
+
+    Ok(issues)
+}
+
+/// Load and apply the config file if it exists. A file that doesn't parse or validate
+/// leaves the current config untouched. Otherwise the entries that couldn't be applied,
+/// e.g. a shortcut owned by another app, are returned and the rest is applied.
+pub fn reload_config_file(app: &tauri::AppHandle) -> Result<Vec<ImportIssue>, CrossZonesError> {
+    let path = config_file_path(app)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| CrossZonesError::file(&path.display().to_string(), e))?;
+    let config = parse_config(app, &contents).map_err(CrossZonesError::Validation)?;
+    apply_config(app, config)
+}
+
+fn reload_and_report(app: &tauri::AppHandle) {
+    match reload_config_file(app) {
+        Ok(issues) => {
+            for issue in &issues {
+                eprintln!(
+                    "Config file entry {} was not applied: {}",
+                    issue.entry, issue.message
+                );
+            }
+            let _ = app.emit("config-file-applied", &issues);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            let _ = app.emit("config-file-error", &e);
+        }
+    }
+}
+
+fn modified_time(path: &PathBuf) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Apply the config file and keep watching it for changes
+pub fn setup(app_handle: &tauri::AppHandle) {
+    let path = match config_file_path(app_handle) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    let mut last_modified = modified_time(&path);
+    if last_modified.is_some() {
+        reload_and_report(app_handle);
+    }
+
+    let app_handle = app_handle.clone();
+    thread::spawn(move || loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let modified = modified_time(&path);
+        if modified != last_modified {
+            last_modified = modified;
+            if modified.is_some() {
+                reload_and_report(&app_handle);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub fn reload_config(app: tauri::AppHandle) -> Result<Vec<ImportIssue>, CrossZonesError> {
+    reload_config_file(&app)
+}
+
+#[tauri::command]
+pub fn get_config_file_path(app: tauri::AppHandle) -> Result<String, CrossZonesError> {
+    config_file_path(&app).map(|path| path.display().to_string())
+}
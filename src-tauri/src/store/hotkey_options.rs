@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::Result;
+
+/// Options are kept by shortcut, apart from the actions, so they apply whichever
+/// hotkey profile binds the shortcut
+pub const HOTKEY_OPTIONS_STORE_NAME: &str = "hotkey_options.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum ScopeMode {
+    /// The hotkey only works in the listed applications
+    Include,
+    /// The hotkey works everywhere but in the listed applications
+    Exclude,
+}
+
+/// Applications a hotkey is limited to or left out of
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct AppScope {
+    pub mode: ScopeMode,
+    /// Executable names on Windows, e.g. `Code.exe`, and bundle ids on macOS, e.g.
+    /// `com.microsoft.VSCode`
+    pub apps: Vec<String>,
+}
+
+impl AppScope {
+    /// Whether the hotkey works while `app` has focus. With the focused application
+    /// unknown, only an exclude list lets the hotkey work.
+    pub fn allows(&self, app: Option<&str>) -> bool {
+        let listed = app.is_some_and(|app| self.apps.iter().any(|listed| app_matches(listed, app)));
+        match self.mode {
+            ScopeMode::Include => listed,
+            ScopeMode::Exclude => !listed,
+        }
+    }
+}
+
+/// Case-insensitive, and `.exe` may be left out
+pub fn app_matches(listed: &str, app: &str) -> bool {
+    let strip = |name: &str| {
+        let name = name.trim().to_lowercase();
+        name.strip_suffix(".exe")
+            .map(str::to_string)
+            .unwrap_or(name)
+    };
+    strip(listed) == strip(app)
+}
+
+/// Which window a hotkey acts on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum WindowTarget {
+    /// The focused window
+    #[default]
+    Focused,
+    /// The window under the mouse cursor, focused or not
+    UnderCursor,
+    /// The topmost window on the display the mouse cursor is on
+    CursorDisplay,
+}
+
+/// Per-binding settings that aren't part of the actions
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HotkeyOptions {
+    /// Applications the hotkey is limited to or left out of. `None` works everywhere.
+    #[serde(default)]
+    pub scope: Option<AppScope>,
+    #[serde(default)]
+    pub target: WindowTarget,
+}
+
+/// The options for a shortcut, or the defaults if it has none
+pub fn hotkey_options(app: &tauri::AppHandle, shortcut: &str) -> Result<HotkeyOptions> {
+    let store = app.store(HOTKEY_OPTIONS_STORE_NAME)?;
+
+    match store.get(shortcut) {
+        Some(value) => Ok(serde_json::from_value(value)?),
+        None => Ok(HotkeyOptions::default()),
+    }
+}
+
+/// Store the options for a shortcut. Default options are removed instead.
+pub fn set_hotkey_options(
+    app: &tauri::AppHandle,
+    shortcut: &str,
+    options: &HotkeyOptions,
+) -> Result<()> {
+    let store = app.store(HOTKEY_OPTIONS_STORE_NAME)?;
+
+    if *options == HotkeyOptions::default() {
+        store.delete(shortcut);
+    } else {
+        store.set(shortcut, serde_json::to_value(options)?);
+    }
+    store.save()?;
+
+    Ok(())
+}
+
+pub fn remove_hotkey_options(app: &tauri::AppHandle, shortcut: &str) -> Result<()> {
+    let store = app.store(HOTKEY_OPTIONS_STORE_NAME)?;
+    store.delete(shortcut);
+    store.save()?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::Result;
+use crate::snapping::action::LayoutAction;
+use crate::store::hotkeys::hotkey_bindings;
+
+pub const HOTKEY_PROFILES_STORE_NAME: &str = "hotkey_profiles.json";
+
+/// The profile created from the existing hotkeys the first time profiles are used
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// A named set of hotkeys, e.g. for a laptop or a docked setup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HotkeyProfile {
+    pub id: String,
+    pub name: String,
+    /// Shortcut → actions. The active profile's hotkeys live in the hotkeys store and
+    /// are only copied here when switching away from it.
+    pub hotkeys: BTreeMap<String, Vec<LayoutAction>>,
+}
+
+/// The hotkeys that are registered right now
+pub fn live_hotkeys(app: &tauri::AppHandle) -> Result<BTreeMap<String, Vec<LayoutAction>>> {
+    Ok(hotkey_bindings(app)?
+        .into_iter()
+        .map(|binding| (binding.shortcut, binding.actions))
+        .collect())
+}
+
+/// All stored profiles, with a default one holding the current hotkeys if there are none yet
+pub fn stored_profiles(app: &tauri::AppHandle) -> Result<Vec<HotkeyProfile>> {
+    let store = app.store(HOTKEY_PROFILES_STORE_NAME)?;
+
+    if let Some(profiles_value) = store.get("profiles") {
+        let profiles: Vec<HotkeyProfile> = serde_json::from_value(profiles_value.clone())?;
+        if !profiles.is_empty() {
+            return Ok(profiles);
+        }
+    }
+
+    Ok(vec![HotkeyProfile {
+        id: DEFAULT_PROFILE_ID.to_string(),
+        name: "Default".to_string(),
+        hotkeys: live_hotkeys(app)?,
+    }])
+}
+
+pub fn save_profiles(app: &tauri::AppHandle, profiles: &[HotkeyProfile]) -> Result<()> {
+    let store = app.store(HOTKEY_PROFILES_STORE_NAME)?;
+
+    store.set("profiles", serde_json::to_value(profiles)?);
+
+    store.save()?;
+
+    Ok(())
+}
+
+pub fn active_profile_id(app: &tauri::AppHandle) -> Result<String> {
+    let store = app.store(HOTKEY_PROFILES_STORE_NAME)?;
+
+    if let Some(active_id_value) = store.get("active_profile_id") {
+        let active_id: String = serde_json::from_value(active_id_value.clone())?;
+        Ok(active_id)
+    } else {
+        Ok(DEFAULT_PROFILE_ID.to_string())
+    }
+}
+
+pub fn set_active_profile_id(app: &tauri::AppHandle, profile_id: &str) -> Result<()> {
+    let store = app.store(HOTKEY_PROFILES_STORE_NAME)?;
+
+    store.set("active_profile_id", serde_json::to_value(profile_id)?);
+
+    store.save()?;
+
+    Ok(())
+}
@@ -1 +1,54 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::Result;
+use crate::snapping::action::LayoutAction;
+use crate::store::migrations::SCHEMA_VERSION_KEY;
+
 pub const HOTKEYS_STORE_NAME: &str = "hotkeys.json";
+
+/// A shortcut and the actions it runs, in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HotkeyBinding {
+    pub shortcut: String,
+    pub actions: Vec<LayoutAction>,
+}
+
+/// All shortcut → actions entries in the hotkeys store, leaving out the schema version
+pub fn hotkey_entries(app: &tauri::AppHandle) -> Result<Vec<(String, JsonValue)>> {
+    let store = app.store(HOTKEYS_STORE_NAME)?;
+
+    Ok(store
+        .entries()
+        .into_iter()
+        .filter(|(key, _)| key != SCHEMA_VERSION_KEY)
+        .collect())
+}
+
+/// Read the actions stored for a shortcut
+pub fn read_actions(value: &JsonValue) -> std::result::Result<Vec<LayoutAction>, String> {
+    let actions: Vec<LayoutAction> = serde_json::from_value(value.clone())
+        .map_err(|e| format!("Invalid actions {}: {}", value, e))?;
+    if actions.is_empty() {
+        return Err("No actions".to_string());
+    }
+    Ok(actions)
+}
+
+/// Every stored binding. Entries that can't be read are skipped, the hotkey report
+/// lists them as inactive.
+pub fn hotkey_bindings(app: &tauri::AppHandle) -> Result<Vec<HotkeyBinding>> {
+    Ok(hotkey_entries(app)?
+        .into_iter()
+        .filter_map(|(shortcut, value)| match read_actions(&value) {
+            Ok(actions) => Some(HotkeyBinding { shortcut, actions }),
+            Err(e) => {
+                eprintln!("Skipping unreadable hotkey {}: {}", shortcut, e);
+                None
+            }
+        })
+        .collect())
+}
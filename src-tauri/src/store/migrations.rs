@@ -0,0 +1,239 @@
+use serde_json::{Map, Value as JsonValue};
+use tauri_plugin_store::StoreExt;
+
+use crate::store::chords::CHORDS_STORE_NAME;
+use crate::store::config_file::CONFIG_FILE_STORE_NAME;
+use crate::store::hotkey_options::HOTKEY_OPTIONS_STORE_NAME;
+use crate::store::hotkey_profiles::HOTKEY_PROFILES_STORE_NAME;
+use crate::store::hotkeys::HOTKEYS_STORE_NAME;
+use crate::store::settings::SETTINGS_STORE_NAME;
+use crate::store::window_rules::WINDOW_RULES_STORE_NAME;
+use crate::store::zone_layouts::{generate_id, ZONE_LAYOUTS_STORE_NAME};
+
+/// Key holding the schema version in every store
+pub const SCHEMA_VERSION_KEY: &str = "__schema_version";
+
+/// Upgrades store entries by one version. Migrations work on plain JSON so they don't
+/// depend on the current shape of the Rust types.
+type Migration = fn(&mut Map<String, JsonValue>) -> Result<(), String>;
+
+struct StoreSchema {
+    store_name: &'static str,
+    /// `migrations[n]` upgrades version `n` to `n + 1`, so the current version is the length
+    migrations: &'static [Migration],
+}
+
+const SCHEMAS: &[StoreSchema] = &[
+    StoreSchema {
+        store_name: SETTINGS_STORE_NAME,
+        migrations: &[settings_v1],
+    },
+    StoreSchema {
+        store_name: HOTKEYS_STORE_NAME,
+        migrations: &[hotkeys_v1, hotkeys_v2],
+    },
+    StoreSchema {
+        store_name: ZONE_LAYOUTS_STORE_NAME,
+        migrations: &[zone_layouts_v1],
+    },
+    StoreSchema {
+        store_name: CHORDS_STORE_NAME,
+        migrations: &[],
+    },
+    StoreSchema {
+        store_name: HOTKEY_PROFILES_STORE_NAME,
+        migrations: &[],
+    },
+    StoreSchema {
+        store_name: HOTKEY_OPTIONS_STORE_NAME,
+        migrations: &[],
+    },
+    StoreSchema {
+        store_name: WINDOW_RULES_STORE_NAME,
+        migrations: &[],
+    },
+    StoreSchema {
+        store_name: CONFIG_FILE_STORE_NAME,
+        migrations: &[],
+    },
+];
+
+/// Modifier key names are matched in lowercase by drag detection
+fn settings_v1(entries: &mut Map<String, JsonValue>) -> Result<(), String> {
+    if let Some(JsonValue::String(key)) = entries.get_mut("zone_drag_modifier_key") {
+        *key = key.to_lowercase();
+    }
+    Ok(())
+}
+
+/// Convert a legacy hotkey action into the `{action, zone_number, layout_id}` shape that
+/// `LayoutAction` is serialized as.
+/// Older versions stored the serialized `LayoutAction` (`"left-half"`, `{"apply-zone": 1}`)
+/// or the bare action name.
+fn legacy_action_payload(value: &str) -> Option<JsonValue> {
+    let parsed: JsonValue =
+        serde_json::from_str(value).unwrap_or_else(|_| JsonValue::String(value.to_string()));
+
+    match parsed {
+        JsonValue::Object(ref object) if object.contains_key("action") => Some(parsed),
+        JsonValue::String(action) if !action.is_empty() => {
+            Some(serde_json::json!({ "action": action }))
+        }
+        JsonValue::Object(object) if object.len() == 1 => {
+            let (action, value) = object.into_iter().next()?;
+            match action.as_str() {
+                "apply-zone" => Some(serde_json::json!({
+                    "action": action,
+                    "zone_number": value.as_u64()?,
+                })),
+                "activate-layout" => Some(serde_json::json!({
+                    "action": action,
+                    "layout_id": value.as_str()?,
+                })),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Rewrite every hotkey action as a serialized `LayoutAction`, dropping unreadable entries
+fn hotkeys_v1(entries: &mut Map<String, JsonValue>) -> Result<(), String> {
+    let shortcuts: Vec<String> = entries.keys().cloned().collect();
+
+    for shortcut in shortcuts {
+        let payload = match &entries[&shortcut] {
+            JsonValue::String(value) => legacy_action_payload(value),
+            value => legacy_action_payload(&value.to_string()),
+        };
+
+        match payload {
+            Some(payload) => {
+                entries.insert(shortcut, JsonValue::String(payload.to_string()));
+            }
+            None => {
+                eprintln!("Dropping unreadable hotkey {}: {}", shortcut, entries[&shortcut]);
+                entries.remove(&shortcut);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Store every hotkey as a list of actions, so a shortcut can run a macro
+fn hotkeys_v2(entries: &mut Map<String, JsonValue>) -> Result<(), String> {
+    let shortcuts: Vec<String> = entries.keys().cloned().collect();
+
+    for shortcut in shortcuts {
+        // Version 1 stores the action payload as a JSON string
+        let payload = entries[&shortcut]
+            .as_str()
+            .ok_or_else(|| "Action is not a string".to_string())
+            .and_then(|value| serde_json::from_str::<JsonValue>(value).map_err(|e| e.to_string()))
+            .and_then(|payload| match payload {
+                JsonValue::Object(_) => Ok(payload),
+                payload => Err(format!("Invalid action {}", payload)),
+            });
+
+        match payload {
+            Ok(payload) => {
+                entries.insert(shortcut, serde_json::json!([payload]));
+            }
+            Err(e) => {
+                eprintln!("Dropping unreadable hotkey {}: {}", shortcut, e);
+                entries.remove(&shortcut);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Give zones without an id or number one, so every layout deserializes
+fn zone_layouts_v1(entries: &mut Map<String, JsonValue>) -> Result<(), String> {
+    let Some(JsonValue::Array(layouts)) = entries.get_mut("layouts") else {
+        return Ok(());
+    };
+
+    for layout in layouts {
+        let Some(JsonValue::Array(zones)) = layout.get_mut("zones") else {
+            continue;
+        };
+        for (index, zone) in zones.iter_mut().enumerate() {
+            let Some(zone) = zone.as_object_mut() else {
+                continue;
+            };
+            if !zone.get("id").is_some_and(|id| id.is_string()) {
+                zone.insert("id".to_string(), JsonValue::String(generate_id("zone")));
+            }
+            if !zone.get("number").is_some_and(|number| number.is_u64()) {
+                zone.insert("number".to_string(), JsonValue::from(index as u64 + 1));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run any pending migrations for a single store, backing up its file first
+fn migrate_store(app: &tauri::AppHandle, schema: &StoreSchema) -> Result<(), String> {
+    let store = app
+        .store(schema.store_name)
+        .map_err(|e| format!("Failed to open store: {}", e))?;
+
+    let target = schema.migrations.len() as u64;
+    let version = store
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+
+    if version == target {
+        return Ok(());
+    }
+    if version > target {
+        return Err(format!(
+            "{} has schema version {}, newer than the supported version {}",
+            schema.store_name, version, target
+        ));
+    }
+
+    let mut entries: Map<String, JsonValue> = store
+        .entries()
+        .into_iter()
+        .filter(|(key, _)| key != SCHEMA_VERSION_KEY)
+        .collect();
+
+    // Fresh installs have nothing to migrate or back up
+    if !entries.is_empty() {
+        let path = tauri_plugin_store::resolve_store_path(app, schema.store_name)
+            .map_err(|e| format!("Failed to resolve store path: {}", e))?;
+        if path.exists() {
+            let backup = path.with_extension(format!("v{}.bak", version));
+            std::fs::copy(&path, &backup)
+                .map_err(|e| format!("Failed to back up {}: {}", path.display(), e))?;
+        }
+
+        for migration in &schema.migrations[version as usize..] {
+            migration(&mut entries)?;
+        }
+    }
+
+    store.clear();
+    for (key, value) in entries {
+        store.set(key, value);
+    }
+    store.set(SCHEMA_VERSION_KEY, target);
+    store.save().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Bring every store up to its current schema version. Must run before anything reads the stores.
+pub fn run_migrations(app: &tauri::AppHandle) {
+    for schema in SCHEMAS {
+        if let Err(e) = migrate_store(app, schema) {
+            eprintln!("Failed to migrate {}: {}", schema.store_name, e);
+        }
+    }
+}
@@ -1,3 +1,11 @@
+pub mod bundle;
+pub mod cache;
+pub mod chords;
+pub mod config_file;
+pub mod hotkey_options;
+pub mod hotkey_profiles;
 pub mod hotkeys;
+pub mod migrations;
 pub mod settings;
+pub mod window_rules;
 pub mod zone_layouts;
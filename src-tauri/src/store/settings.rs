@@ -1,36 +1,172 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
-use tauri::Manager;
+use tauri::{Emitter, Manager};
+use tauri_plugin_global_shortcut::Shortcut;
 use tauri_plugin_store::{Store, StoreExt};
 use ts_rs::TS;
 
+use crate::error::CrossZonesError;
+
 pub const SETTINGS_STORE_NAME: &str = "settings.json";
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+/// Modifier keys that can be held to snap dragged windows to zones
+pub const ZONE_DRAG_MODIFIER_KEYS: &[&str] = &["control", "alt", "shift", "super"];
+
+/// Event emitted with the list of `SettingChange`s whenever settings are updated
+pub const SETTINGS_CHANGED_EVENT: &str = "settings-changed";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(default)]
 #[ts(export)]
 pub struct Settings {
-    #[serde(default)]
     pub auto_start: bool,
-    #[serde(default)]
     pub start_minimized: bool,
-    #[serde(default)]
     pub close_to_system_tray: bool,
-    #[serde(default)]
     pub show_layout_activation_notification: bool,
-    #[serde(default)]
     pub zone_drag_modifier_key: Option<String>, // e.g., "control", "alt", "shift", "super"
     /// When true, show the zone overlay during drag-and-drop (when modifier is held). When false, zones still apply on drop but no visual indicator is shown.
-    #[serde(default)]
     pub show_zone_drag_overlay: bool,
     /// Opacity of the zone overlay during drag (0.0–1.0). Default 0.25 when unset.
-    #[serde(default = "default_zone_overlay_opacity")]
     pub zone_overlay_opacity: f32,
+    /// Shortcut that starts a chord, e.g. "Control+Alt+Space". Chords are off when unset.
+    pub leader_key: Option<String>,
+    /// How long to wait for the next key of a chord, in milliseconds
+    pub chord_timeout_ms: u32,
+    /// Show the keys that can follow while a chord is in progress
+    pub show_chord_hints: bool,
+    /// Holding a hotkey previews where the window goes, releasing applies it and Esc cancels
+    pub hold_to_preview: bool,
+    /// Repeat move and resize hotkeys while they are held
+    pub key_repeat: bool,
+    /// How long a hotkey is held before it starts repeating, in milliseconds
+    pub key_repeat_delay_ms: u32,
+    /// Time between the first repeats, in milliseconds. Repeats speed up from there.
+    pub key_repeat_interval_ms: u32,
+    /// The shortest time between repeats, in milliseconds
+    pub key_repeat_min_interval_ms: u32,
+    /// Leave hotkeys to the focused application while it is fullscreen, e.g. a game
+    pub suppress_hotkeys_in_fullscreen: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            auto_start: false,
+            start_minimized: false,
+            close_to_system_tray: false,
+            show_layout_activation_notification: false,
+            zone_drag_modifier_key: None,
+            show_zone_drag_overlay: true,
+            zone_overlay_opacity: 0.25,
+            leader_key: None,
+            chord_timeout_ms: 1500,
+            show_chord_hints: true,
+            hold_to_preview: false,
+            key_repeat: true,
+            key_repeat_delay_ms: 400,
+            key_repeat_interval_ms: 120,
+            key_repeat_min_interval_ms: 30,
+            suppress_hotkeys_in_fullscreen: true,
+        }
+    }
+}
+
+impl Settings {
+    /// Check that every field holds a value the rest of the app can use
+    pub fn validate(&self) -> Result<(), SettingsError> {
+        if !(0.0..=1.0).contains(&self.zone_overlay_opacity) {
+            return Err(SettingsError::Invalid(format!(
+                "zone_overlay_opacity must be between 0 and 1, got {}",
+                self.zone_overlay_opacity
+            )));
+        }
+
+        if let Some(key) = &self.zone_drag_modifier_key {
+            if !ZONE_DRAG_MODIFIER_KEYS.contains(&key.as_str()) {
+                return Err(SettingsError::Invalid(format!(
+                    "Unknown zone_drag_modifier_key \"{}\", expected one of {}",
+                    key,
+                    ZONE_DRAG_MODIFIER_KEYS.join(", ")
+                )));
+            }
+        }
+
+        if let Some(key) = &self.leader_key {
+            Shortcut::from_str(key).map_err(|e| {
+                SettingsError::Invalid(format!("Invalid leader_key \"{}\": {}", key, e))
+            })?;
+        }
+
+        if !(100..=10_000).contains(&self.chord_timeout_ms) {
+            return Err(SettingsError::Invalid(format!(
+                "chord_timeout_ms must be between 100 and 10000, got {}",
+                self.chord_timeout_ms
+            )));
+        }
+
+        if !(50..=5_000).contains(&self.key_repeat_delay_ms) {
+            return Err(SettingsError::Invalid(format!(
+                "key_repeat_delay_ms must be between 50 and 5000, got {}",
+                self.key_repeat_delay_ms
+            )));
+        }
+
+        // Faster repeats would only queue up window moves the OS can't keep up with
+        if !(16..=2_000).contains(&self.key_repeat_min_interval_ms) {
+            return Err(SettingsError::Invalid(format!(
+                "key_repeat_min_interval_ms must be between 16 and 2000, got {}",
+                self.key_repeat_min_interval_ms
+            )));
+        }
+
+        if !(self.key_repeat_min_interval_ms..=2_000).contains(&self.key_repeat_interval_ms) {
+            return Err(SettingsError::Invalid(format!(
+                "key_repeat_interval_ms must be between key_repeat_min_interval_ms and 2000, got {}",
+                self.key_repeat_interval_ms
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn to_map(&self) -> Result<serde_json::Map<String, JsonValue>, SettingsError> {
+        match serde_json::to_value(self)? {
+            JsonValue::Object(values) => Ok(values),
+            _ => unreachable!("Settings always serializes to an object"),
+        }
+    }
+
+    /// The settings whose value differs between `self` and `other`
+    pub fn diff(&self, other: &Settings) -> Result<Vec<SettingChange>, SettingsError> {
+        let old = self.to_map()?;
+        let new = other.to_map()?;
+
+        Ok(new
+            .into_iter()
+            .filter_map(|(key, new_value)| {
+                let old_value = old.get(&key).cloned().unwrap_or(JsonValue::Null);
+                (old_value != new_value).then_some(SettingChange {
+                    key,
+                    old_value,
+                    new_value,
+                })
+            })
+            .collect())
+    }
 }
 
-fn default_zone_overlay_opacity() -> f32 {
-    0.25
+/// A single setting that changed, sent with the `settings-changed` event
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SettingChange {
+    pub key: String,
+    #[ts(type = "unknown")]
+    pub old_value: JsonValue,
+    #[ts(type = "unknown")]
+    pub new_value: JsonValue,
 }
 
 /// The main settings store
@@ -44,6 +180,8 @@ pub enum SettingsError {
     Store(#[from] tauri_plugin_store::Error),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("Invalid settings: {0}")]
+    Invalid(String),
 }
 
 impl SettingsStore {
@@ -53,21 +191,54 @@ impl SettingsStore {
         })
     }
 
+    /// Load all settings, using the defaults for any that are unset. A stored value that
+    /// can't be read or is out of range, e.g. after editing the file by hand, falls back
+    /// to its default on its own instead of failing the others.
+    pub fn load_all(&self) -> Result<Settings, SettingsError> {
+        let store = self.app_handle.store(SETTINGS_STORE_NAME)?;
+        let mut values = Settings::default().to_map()?;
+
+        let keys: Vec<String> = values.keys().cloned().collect();
+        for key in keys {
+            let Some(value) = store.get(&key) else {
+                continue;
+            };
+            let default = values.insert(key.clone(), value).unwrap_or(JsonValue::Null);
+            let checked = serde_json::from_value::<Settings>(JsonValue::Object(values.clone()))
+                .map_err(SettingsError::from)
+                .and_then(|settings| settings.validate());
+            if let Err(e) = checked {
+                eprintln!("Using the default for invalid setting {}: {}", key, e);
+                values.insert(key, default);
+            }
+        }
+
+        Ok(serde_json::from_value(JsonValue::Object(values))?)
+    }
+
     /// Save all settings
     pub fn save_all(&self, settings: &Settings) -> Result<(), SettingsError> {
         let store = self.app_handle.store(SETTINGS_STORE_NAME)?;
-        // TODO: Actually iterate instead of this
-        store.set("auto_start", settings.auto_start);
-        store.set("start_minimized", settings.start_minimized);
-        store.set("close_to_system_tray", settings.close_to_system_tray);
-        store.set("show_layout_activation_notification", settings.show_layout_activation_notification);
-        store.set("zone_drag_modifier_key", serde_json::to_value(&settings.zone_drag_modifier_key)?);
-        store.set("show_zone_drag_overlay", settings.show_zone_drag_overlay);
-        store.set("zone_overlay_opacity", settings.zone_overlay_opacity);
+        for (key, value) in settings.to_map()? {
+            store.set(key, value);
+        }
         store.save()?;
         Ok(())
     }
 
+    /// Validate and save all settings, then emit `settings-changed` with what changed
+    pub fn update(&self, settings: &Settings) -> Result<Vec<SettingChange>, SettingsError> {
+        settings.validate()?;
+
+        let changes = self.load_all()?.diff(settings)?;
+        self.save_all(settings)?;
+
+        if !changes.is_empty() {
+            let _ = self.app_handle.emit(SETTINGS_CHANGED_EVENT, &changes);
+        }
+        Ok(changes)
+    }
+
     /// Get a specific setting
     pub fn get<T: for<'de> serde::Deserialize<'de>>(
         &self,
@@ -77,15 +248,6 @@ impl SettingsStore {
         let value = store.get(field).unwrap_or(serde_json::Value::Null);
         Ok(serde_json::from_value(value).ok())
     }
-
-    /// Set a specific setting
-    pub fn set<T: Serialize>(&self, field: &str, value: T) -> Result<(), SettingsError> {
-        let store = self.app_handle.store(SETTINGS_STORE_NAME)?;
-        let json_value = serde_json::to_value(value)?;
-        store.set(field, json_value);
-        store.save()?;
-        Ok(())
-    }
 }
 
 // Helper methods for common settings
@@ -94,57 +256,49 @@ impl SettingsStore {
         self.get("auto_start").map(|v| v.unwrap_or(false))
     }
 
-    pub fn set_auto_start(&self, value: bool) -> Result<(), SettingsError> {
-        self.set("auto_start", value)
-    }
-
     pub fn get_start_minimized(&self) -> Result<bool, SettingsError> {
         self.get("start_minimized").map(|v| v.unwrap_or(false))
     }
 
-    pub fn set_start_minimized(&self, value: bool) -> Result<(), SettingsError> {
-        self.set("start_minimized", value)
-    }
-
     pub fn get_close_to_system_tray(&self) -> Result<bool, SettingsError> {
         self.get("close_to_system_tray").map(|v| v.unwrap_or(false))
     }
 
-    pub fn set_close_to_system_tray(&self, value: bool) -> Result<(), SettingsError> {
-        self.set("close_to_system_tray", value)
-    }
-
     pub fn get_show_layout_activation_notification(&self) -> Result<bool, SettingsError> {
         self.get("show_layout_activation_notification").map(|v| v.unwrap_or(false))
     }
 
-    pub fn set_show_layout_activation_notification(&self, value: bool) -> Result<(), SettingsError> {
-        self.set("show_layout_activation_notification", value)
-    }
-
     pub fn get_zone_drag_modifier_key(&self) -> Result<Option<String>, SettingsError> {
         self.get("zone_drag_modifier_key")
     }
 
-    pub fn set_zone_drag_modifier_key(&self, value: Option<String>) -> Result<(), SettingsError> {
-        self.set("zone_drag_modifier_key", value)
-    }
-
     /// Whether to show the zone overlay during drag-and-drop when the modifier key is held. Defaults to true when unset.
     pub fn get_show_zone_drag_overlay(&self) -> Result<bool, SettingsError> {
-        self.get("show_zone_drag_overlay").map(|v| v.unwrap_or(true))
-    }
-
-    pub fn set_show_zone_drag_overlay(&self, value: bool) -> Result<(), SettingsError> {
-        self.set("show_zone_drag_overlay", value)
+        self.get("show_zone_drag_overlay")
+            .map(|v| v.unwrap_or(Settings::default().show_zone_drag_overlay))
     }
 
     /// Zone overlay opacity (0.0–1.0). Defaults to 0.25 when unset.
     pub fn get_zone_overlay_opacity(&self) -> Result<f32, SettingsError> {
-        self.get("zone_overlay_opacity").map(|v| v.unwrap_or(0.25))
+        self.get("zone_overlay_opacity")
+            .map(|v| v.unwrap_or(Settings::default().zone_overlay_opacity))
     }
+}
 
-    pub fn set_zone_overlay_opacity(&self, value: f32) -> Result<(), SettingsError> {
-        self.set("zone_overlay_opacity", value)
-    }
+#[tauri::command]
+pub fn get_settings(app: tauri::AppHandle) -> Result<Settings, CrossZonesError> {
+    Ok(SettingsStore::new(&app)?.load_all()?)
+}
+
+#[tauri::command]
+pub fn update_settings(
+    app: tauri::AppHandle,
+    settings: Settings,
+) -> Result<Vec<SettingChange>, CrossZonesError> {
+    Ok(SettingsStore::new(&app)?.update(&settings)?)
+}
+
+#[tauri::command]
+pub fn reset_settings(app: tauri::AppHandle) -> Result<Vec<SettingChange>, CrossZonesError> {
+    Ok(SettingsStore::new(&app)?.update(&Settings::default())?)
 }
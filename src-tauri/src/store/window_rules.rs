@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use crate::error::Result;
+use crate::snapping::action::LayoutAction;
+
+pub const WINDOW_RULES_STORE_NAME: &str = "window_rules.json";
+
+/// What a rule does with a window that matches it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+#[ts(export)]
+pub enum RuleAction {
+    /// Put the window in a zone of a layout, whether or not the layout is active
+    Zone { layout_id: String, zone_number: u32 },
+    /// Run a snapping action on the window
+    Action { action: LayoutAction },
+    /// Move the window to a display, by its index in the screen list
+    Display { index: u32 },
+    /// Leave the window where it opened, without trying the rules after this one
+    Ignore,
+}
+
+/// Which windows a rule or an exclusion applies to. Every criterion that is set has to
+/// match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WindowMatch {
+    /// Executable name on Windows, e.g. `Code.exe`, or bundle id on macOS, e.g.
+    /// `com.microsoft.VSCode`
+    #[serde(default)]
+    pub app: Option<String>,
+    /// Regular expression searched for in the window title
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Window class on Windows, e.g. `Chrome_WidgetWin_1`, or subrole on macOS, e.g.
+    /// `AXStandardWindow`
+    #[serde(default)]
+    pub class: Option<String>,
+}
+
+impl WindowMatch {
+    fn app(app: &str) -> Self {
+        WindowMatch {
+            app: Some(app.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn class(class: &str) -> Self {
+        WindowMatch {
+            class: Some(class.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Whether no criterion is set, so it would match every window
+    pub fn is_empty(&self) -> bool {
+        self.app.is_none() && self.title.is_none() && self.class.is_none()
+    }
+}
+
+/// Places windows as they open
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct WindowRule {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub criteria: WindowMatch,
+    pub action: RuleAction,
+}
+
+/// The stored rules, in the order they are tried
+pub fn window_rules(app: &tauri::AppHandle) -> Result<Vec<WindowRule>> {
+    let store = app.store(WINDOW_RULES_STORE_NAME)?;
+
+    match store.get("rules") {
+        Some(value) => Ok(serde_json::from_value(value)?),
+        None => Ok(Vec::new()),
+    }
+}
+
+pub fn save_window_rules(app: &tauri::AppHandle, rules: &[WindowRule]) -> Result<()> {
+    let store = app.store(WINDOW_RULES_STORE_NAME)?;
+
+    store.set("rules", serde_json::to_value(rules)?);
+
+    store.save()?;
+
+    Ok(())
+}
+
+/// The desktop, taskbar, Dock and other shell windows, which are never worth snapping
+pub fn default_exclusions() -> Vec<WindowMatch> {
+    if cfg!(target_os = "macos") {
+        vec![
+            WindowMatch::app("com.apple.dock"),
+            WindowMatch::app("com.apple.controlcenter"),
+            WindowMatch::app("com.apple.notificationcenterui"),
+            WindowMatch::app("com.apple.Spotlight"),
+            WindowMatch::app("com.apple.WindowManager"),
+        ]
+    } else {
+        vec![
+            // The desktop
+            WindowMatch::class("Progman"),
+            WindowMatch::class("WorkerW"),
+            // The taskbars
+            WindowMatch::class("Shell_TrayWnd"),
+            WindowMatch::class("Shell_SecondaryTrayWnd"),
+            // Start, Search and the notification flyouts. Apps from the Store have their
+            // core window inside a frame window, so only the shell's are top-level. Matched
+            // by class rather than by app, which would look up the process on every click.
+            WindowMatch::class("Windows.UI.Core.CoreWindow"),
+        ]
+    }
+}
+
+/// Windows that are never snapped, dragged to zones or placed by rules. Our own windows
+/// are left out of that too, without being listed.
+pub fn window_exclusions(app: &tauri::AppHandle) -> Result<Vec<WindowMatch>> {
+    let store = app.store(WINDOW_RULES_STORE_NAME)?;
+
+    match store.get("exclusions") {
+        Some(value) => Ok(serde_json::from_value(value)?),
+        None => Ok(default_exclusions()),
+    }
+}
+
+pub fn save_window_exclusions(app: &tauri::AppHandle, exclusions: &[WindowMatch]) -> Result<()> {
+    let store = app.store(WINDOW_RULES_STORE_NAME)?;
+
+    store.set("exclusions", serde_json::to_value(exclusions)?);
+
+    store.save()?;
+
+    Ok(())
+}
+
+/// Go back to the default exclusions
+pub fn reset_window_exclusions(app: &tauri::AppHandle) -> Result<()> {
+    let store = app.store(WINDOW_RULES_STORE_NAME)?;
+    store.delete("exclusions");
+    store.save()?;
+
+    Ok(())
+}
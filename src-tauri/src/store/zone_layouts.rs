@@ -1,11 +1,28 @@
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tauri_plugin_store::StoreExt;
 use ts_rs::TS;
 
+use crate::error::{CrossZonesError, Result};
 use crate::snapping::common::ScreenDimensions;
+use crate::zones::split_tree::SplitNode;
+use crate::zones::validation::validate_layout;
 
 pub const ZONE_LAYOUTS_STORE_NAME: &str = "zone_layouts.json";
 
+static ID_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+/// Generate a unique id in the same `<prefix>-<millis>-<suffix>` shape the frontend uses
+pub fn generate_id(prefix: &str) -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let counter = ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{}-{}", prefix, millis, counter)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct Zone {
@@ -27,6 +44,10 @@ pub struct ZoneLayout {
     pub screen_width: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "screenHeight")]
     pub screen_height: Option<u32>,
+    /// Optional split-tree representation. When present, `zones` is compiled from it.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "splitTree")]
+    #[ts(optional)]
+    pub split_tree: Option<SplitNode>,
 }
 
 impl ZoneLayout {
@@ -45,31 +66,50 @@ impl ZoneLayout {
     }
 }
 #[tauri::command]
-pub fn get_all_zone_layouts(app: tauri::AppHandle) -> Result<Vec<ZoneLayout>, String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+pub fn get_all_zone_layouts(app: tauri::AppHandle) -> Result<Vec<ZoneLayout>> {
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     let layouts_json = store.get("layouts");
 
     if let Some(layouts_value) = layouts_json {
-        let layouts: Vec<ZoneLayout> = serde_json::from_value(layouts_value.clone())
-            .map_err(|e| format!("Failed to deserialize layouts: {}", e))?;
+        let layouts: Vec<ZoneLayout> = serde_json::from_value(layouts_value.clone())?;
         Ok(layouts)
     } else {
         Ok(Vec::new())
     }
 }
 
+/// Sync the flat zone list with the split tree, and reject layouts that can't be saved
+pub fn check_zone_layout(layout: &mut ZoneLayout) -> Result<()> {
+    if let Some(tree) = &layout.split_tree {
+        tree.validate()
+            .map_err(|e| CrossZonesError::Validation(format!("Invalid zone layout: {}", e)))?;
+        layout.zones = tree.to_zones();
+    }
+
+    let errors: Vec<String> = validate_layout(layout)
+        .into_iter()
+        .filter(|issue| issue.is_error())
+        .map(|issue| issue.message)
+        .collect();
+    if !errors.is_empty() {
+        return Err(CrossZonesError::Validation(format!(
+            "Invalid zone layout: {}",
+            errors.join("; ")
+        )));
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
-pub fn save_zone_layout(app: tauri::AppHandle, layout: ZoneLayout) -> Result<(), String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+pub fn save_zone_layout(app: tauri::AppHandle, mut layout: ZoneLayout) -> Result<()> {
+    check_zone_layout(&mut layout)?;
+
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     let mut layouts: Vec<ZoneLayout> = if let Some(layouts_value) = store.get("layouts") {
-        serde_json::from_value(layouts_value.clone())
-            .map_err(|e| format!("Failed to deserialize layouts: {}", e))?
+        serde_json::from_value(layouts_value.clone())?
     } else {
         Vec::new()
     };
@@ -81,43 +121,28 @@ pub fn save_zone_layout(app: tauri::AppHandle, layout: ZoneLayout) -> Result<(),
         layouts.push(layout);
     }
 
-    store.set(
-        "layouts",
-        serde_json::to_value(&layouts)
-            .map_err(|e| format!("Failed to serialize layouts: {}", e))?,
-    );
+    store.set("layouts", serde_json::to_value(&layouts)?);
 
-    store
-        .save()
-        .map_err(|e| format!("Failed to save store: {}", e))?;
+    store.save()?;
 
     Ok(())
 }
 
 #[tauri::command]
-pub fn delete_zone_layout(app: tauri::AppHandle, layout_id: String) -> Result<(), String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+pub fn delete_zone_layout(app: tauri::AppHandle, layout_id: String) -> Result<()> {
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     let mut layouts: Vec<ZoneLayout> = if let Some(layouts_value) = store.get("layouts") {
-        serde_json::from_value(layouts_value.clone())
-            .map_err(|e| format!("Failed to deserialize layouts: {}", e))?
+        serde_json::from_value(layouts_value.clone())?
     } else {
         return Ok(());
     };
 
     layouts.retain(|l| l.id != layout_id);
 
-    store.set(
-        "layouts",
-        serde_json::to_value(&layouts)
-            .map_err(|e| format!("Failed to serialize layouts: {}", e))?,
-    );
+    store.set("layouts", serde_json::to_value(&layouts)?);
 
-    store
-        .save()
-        .map_err(|e| format!("Failed to save store: {}", e))?;
+    store.save()?;
 
     Ok(())
 }
@@ -126,14 +151,11 @@ pub fn delete_zone_layout(app: tauri::AppHandle, layout_id: String) -> Result<()
 pub fn get_zone_layout(
     app: tauri::AppHandle,
     layout_id: String,
-) -> Result<Option<ZoneLayout>, String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+) -> Result<Option<ZoneLayout>> {
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     let layouts: Vec<ZoneLayout> = if let Some(layouts_value) = store.get("layouts") {
-        serde_json::from_value(layouts_value.clone())
-            .map_err(|e| format!("Failed to deserialize layouts: {}", e))?
+        serde_json::from_value(layouts_value.clone())?
     } else {
         return Ok(None);
     };
@@ -142,14 +164,11 @@ pub fn get_zone_layout(
 }
 
 #[tauri::command]
-pub fn get_active_zone_layout_id(app: tauri::AppHandle) -> Result<Option<String>, String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+pub fn get_active_zone_layout_id(app: tauri::AppHandle) -> Result<Option<String>> {
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     if let Some(active_id_value) = store.get("active_layout_id") {
-        let active_id: String = serde_json::from_value(active_id_value.clone())
-            .map_err(|e| format!("Failed to deserialize active layout ID: {}", e))?;
+        let active_id: String = serde_json::from_value(active_id_value.clone())?;
         Ok(Some(active_id))
     } else {
         Ok(None)
@@ -160,24 +179,16 @@ pub fn get_active_zone_layout_id(app: tauri::AppHandle) -> Result<Option<String>
 pub fn set_active_zone_layout_id(
     app: tauri::AppHandle,
     layout_id: Option<String>,
-) -> Result<(), String> {
-    let store = app
-        .store(ZONE_LAYOUTS_STORE_NAME)
-        .map_err(|e| format!("Failed to open store: {}", e))?;
+) -> Result<()> {
+    let store = app.store(ZONE_LAYOUTS_STORE_NAME)?;
 
     if let Some(id) = layout_id {
-        store.set(
-            "active_layout_id",
-            serde_json::to_value(id)
-                .map_err(|e| format!("Failed to serialize active layout ID: {}", e))?,
-        );
+        store.set("active_layout_id", serde_json::to_value(id)?);
     } else {
         store.delete("active_layout_id");
     }
 
-    store
-        .save()
-        .map_err(|e| format!("Failed to save store: {}", e))?;
+    store.save()?;
 
     Ok(())
 }
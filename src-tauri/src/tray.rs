@@ -1,17 +1,67 @@
 use tauri::{
-    menu::{Menu, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem, Submenu},
     tray::{TrayIconBuilder, TrayIconEvent},
     Manager,
 };
 
+use crate::hotkeys::profiles::activate_profile;
+use crate::store::hotkey_profiles::{active_profile_id, stored_profiles};
 use crate::window::PRIMARY_WINDOW_NAME;
 
+const TRAY_ID: &str = "main";
+
+/// Menu item ids for hotkey profiles are this prefix followed by the profile id
+const HOTKEY_PROFILE_PREFIX: &str = "hotkey-profile:";
+
+fn build_menu(app_handle: &tauri::AppHandle) -> tauri::Result<Menu<tauri::Wry>> {
+    let open_i = MenuItem::with_id(app_handle, "open", "Open", true, None::<&str>)?;
+    let quit_i = MenuItem::with_id(app_handle, "quit", "Quit", true, None::<&str>)?;
+
+    let profiles = stored_profiles(app_handle).unwrap_or_default();
+    let active_id = active_profile_id(app_handle).unwrap_or_default();
+    let profile_items = profiles
+        .iter()
+        .map(|profile| {
+            CheckMenuItem::with_id(
+                app_handle,
+                format!("{}{}", HOTKEY_PROFILE_PREFIX, profile.id),
+                &profile.name,
+                true,
+                profile.id == active_id,
+                None::<&str>,
+            )
+        })
+        .collect::<tauri::Result<Vec<_>>>()?;
+    let profiles_submenu = Submenu::with_items(
+        app_handle,
+        "Hotkey Profile",
+        true,
+        &profile_items
+            .iter()
+            .map(|item| item as &dyn tauri::menu::IsMenuItem<tauri::Wry>)
+            .collect::<Vec<_>>(),
+    )?;
+
+    Menu::with_items(app_handle, &[&open_i, &profiles_submenu, &quit_i])
+}
+
+/// Rebuild the menu, e.g. after hotkey profiles were added or switched
+pub fn update_tray_menu(app_handle: &tauri::AppHandle) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    match build_menu(app_handle) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => eprintln!("Failed to build tray menu: {}", e),
+    }
+}
+
 pub fn setup_tray(app_handle: &tauri::AppHandle) {
-    let open_i = MenuItem::with_id(app_handle, "open", "Open", true, None::<&str>).unwrap();
-    let quit_i = MenuItem::with_id(app_handle, "quit", "Quit", true, None::<&str>).unwrap();
-    let menu = Menu::with_items(app_handle, &[&open_i, &quit_i]).unwrap();
+    let menu = build_menu(app_handle).unwrap();
 
-    let tray = TrayIconBuilder::new()
+    let tray = TrayIconBuilder::with_id(TRAY_ID)
         .icon(app_handle.default_window_icon().unwrap().clone())
         .tooltip("CrossZones")
         .menu(&menu)
@@ -25,7 +75,15 @@ pub fn setup_tray(app_handle: &tauri::AppHandle) {
             "quit" => {
                 app.exit(0);
             }
-            _ => {}
+            id => {
+                if let Some(profile_id) = id.strip_prefix(HOTKEY_PROFILE_PREFIX) {
+                    if let Err(e) = activate_profile(app, profile_id) {
+                        eprintln!("Failed to activate hotkey profile {}: {}", profile_id, e);
+                    }
+                    // Check items toggle themselves when clicked, so reset them either way
+                    update_tray_menu(app);
+                }
+            }
         })
         .build(app_handle)
         .unwrap();
@@ -1,8 +1,19 @@
-use ::accessibility::AXUIElement;
+use ::accessibility::{AXAttribute, AXUIElement};
 use accessibility::{AXUIElementActions, AXUIElementAttributes};
+use accessibility_sys::{
+    kAXErrorSuccess, kAXWindowAttribute, kAXWindowRole, AXUIElementCopyElementAtPosition,
+    AXUIElementGetPid, AXUIElementRef,
+};
+use core_foundation::base::TCFType;
+use core_foundation::string::CFString;
 use core_graphics_types::geometry::{CGPoint, CGRect, CGSize};
 use display_info::DisplayInfo;
-use objc2_app_kit::NSWorkspace;
+use objc2_app_kit::{NSApplicationActivationPolicy, NSRunningApplication, NSWorkspace};
+use objc2_core_foundation as cf;
+use objc2_core_graphics::{
+    kCGNullWindowID, kCGWindowBounds, kCGWindowLayer, kCGWindowOwnerPID, CGEvent,
+    CGRectMakeWithDictionaryRepresentation, CGWindowListCopyWindowInfo, CGWindowListOption,
+};
 use std::cmp;
 
 use crate::snapping::common::ScreenDimensions;
@@ -29,6 +40,193 @@ pub fn get_frontmost_window() -> Result<AXUIElement, String> {
     Err("No window found".to_string())
 }
 
+/// Get the mouse cursor position, with the origin at the top left like window frames
+fn get_cursor_position() -> Result<CGPoint, String> {
+    let event = CGEvent::new(None).ok_or("Failed to get the cursor position")?;
+    let location = CGEvent::location(Some(&event));
+
+    Ok(CGPoint::new(location.x, location.y))
+}
+
+/// Get the window under the mouse cursor on macOS, whether it has focus or not
+pub fn get_window_under_cursor() -> Result<AXUIElement, String> {
+    let cursor = get_cursor_position()?;
+
+    let mut element: AXUIElementRef = std::ptr::null_mut();
+    let error = unsafe {
+        AXUIElementCopyElementAtPosition(
+            AXUIElement::system_wide().as_concrete_TypeRef(),
+            cursor.x as f32,
+            cursor.y as f32,
+            &mut element,
+        )
+    };
+    if error != kAXErrorSuccess || element.is_null() {
+        return Err("No window under the cursor".to_string());
+    }
+    let element = unsafe { AXUIElement::wrap_under_create_rule(element) };
+
+    // The element is usually a control inside the window
+    let window_attribute =
+        AXAttribute::<AXUIElement>::new(&CFString::from_static_string(kAXWindowAttribute));
+    match element.attribute(&window_attribute) {
+        Ok(window) => Ok(window),
+        Err(_) if element.role().is_ok_and(|role| role.to_string() == kAXWindowRole) => {
+            Ok(element)
+        }
+        Err(e) => Err(format!("No window under the cursor: {}", e)),
+    }
+}
+
+/// Get the topmost window on the display under the mouse cursor, of whichever app
+pub fn get_window_on_cursor_display() -> Result<AXUIElement, String> {
+    let cursor = get_cursor_position()?;
+    let screen = DisplayInfo::from_point(cursor.x as i32, cursor.y as i32)
+        .map_err(|e| e.to_string())?;
+
+    // A window belongs to the display with its center
+    let on_screen = |bounds: &cf::CGRect| {
+        let center_x = bounds.origin.x + bounds.size.width / 2.0;
+        let center_y = bounds.origin.y + bounds.size.height / 2.0;
+        center_x >= screen.x as f64
+            && center_x < screen.x as f64 + screen.width as f64
+            && center_y >= screen.y as f64
+            && center_y < screen.y as f64 + screen.height as f64
+    };
+
+    // Listed from the front. Regular windows are on layer 0, the menu bar, Dock and
+    // other system windows above it.
+    let windows = CGWindowListCopyWindowInfo(
+        CGWindowListOption::OptionOnScreenOnly | CGWindowListOption::ExcludeDesktopElements,
+        kCGNullWindowID,
+    )
+    .ok_or("Failed to list windows")?;
+    let windows = unsafe { windows.cast_unchecked::<cf::CFDictionary<cf::CFString, cf::CFType>>() };
+
+    let (pid, bounds) = windows
+        .iter()
+        .find_map(|info| {
+            let number = |key: &cf::CFString| {
+                let value = info.get(key)?;
+                value.downcast_ref::<cf::CFNumber>()?.as_i32()
+            };
+            if number(unsafe { kCGWindowLayer })? != 0 {
+                return None;
+            }
+
+            let bounds = info.get(unsafe { kCGWindowBounds })?;
+            let mut rect = cf::CGRect::default();
+            let parsed = unsafe {
+                CGRectMakeWithDictionaryRepresentation(
+                    Some(bounds.downcast_ref::<cf::CFDictionary>()?),
+                    &mut rect,
+                )
+            };
+            if !parsed || !on_screen(&rect) {
+                return None;
+            }
+            Some((number(unsafe { kCGWindowOwnerPID })?, rect))
+        })
+        .ok_or_else(|| "No window on the display under the cursor".to_string())?;
+
+    // The window list has no accessibility elements, the window is found again among
+    // its app's windows by its frame
+    let app = AXUIElement::application(pid);
+    let windows = app.windows().map_err(|e| format!("Failed to get windows: {}", e))?;
+    let window = windows.iter().find(|window| {
+        window.frame().is_ok_and(|frame| {
+            frame.origin.x == bounds.origin.x
+                && frame.origin.y == bounds.origin.y
+                && frame.size.width == bounds.size.width
+                && frame.size.height == bounds.size.height
+        })
+    });
+    // Otherwise the app's frontmost window on the display
+    let window = window.or_else(|| {
+        windows.iter().find(|window| {
+            window.frame().is_ok_and(|frame| {
+                on_screen(&cf::CGRect::new(
+                    cf::CGPoint::new(frame.origin.x, frame.origin.y),
+                    cf::CGSize::new(frame.size.width, frame.size.height),
+                ))
+            })
+        })
+    });
+
+    window
+        .map(|window| window.clone())
+        .ok_or_else(|| "No window on the display under the cursor".to_string())
+}
+
+/// Get the bundle id of the frontmost application, e.g. `com.microsoft.VSCode`
+pub fn get_frontmost_bundle_id() -> Option<String> {
+    let workspace = NSWorkspace::sharedWorkspace();
+    let frontmost_application = workspace.frontmostApplication()?;
+
+    frontmost_application.bundleIdentifier().map(|id| id.to_string())
+}
+
+/// Get the process id of the app a window belongs to
+pub fn get_window_pid(window: &AXUIElement) -> Option<i32> {
+    let mut pid = 0;
+    let error = unsafe { AXUIElementGetPid(window.as_concrete_TypeRef(), &mut pid) };
+
+    (error == kAXErrorSuccess).then_some(pid)
+}
+
+/// Get the bundle id of a running app, e.g. `com.microsoft.VSCode`
+pub fn get_bundle_id(pid: i32) -> Option<String> {
+    NSRunningApplication::runningApplicationWithProcessIdentifier(pid)?
+        .bundleIdentifier()
+        .map(|id| id.to_string())
+}
+
+/// Get the windows of every app in the Dock, with the app's bundle id. Menu bar and
+/// background apps are left out.
+pub fn get_app_windows() -> Vec<(Option<String>, AXUIElement)> {
+    let workspace = NSWorkspace::sharedWorkspace();
+
+    workspace
+        .runningApplications()
+        .iter()
+        .filter(|application| {
+            application.activationPolicy() == NSApplicationActivationPolicy::Regular
+        })
+        .flat_map(|application| {
+            let bundle_id = application.bundleIdentifier().map(|id| id.to_string());
+            let app = AXUIElement::application(application.processIdentifier());
+            let windows: Vec<AXUIElement> = app
+                .windows()
+                .map(|windows| windows.iter().map(|window| window.clone()).collect())
+                .unwrap_or_default();
+            windows
+                .into_iter()
+                .map(move |window| (bundle_id.clone(), window))
+        })
+        .collect()
+}
+
+/// Whether the frontmost window covers a whole display, menu bar included, as native
+/// fullscreen windows and fullscreen games do
+pub fn is_fullscreen_app_focused() -> bool {
+    let Ok(window) = get_frontmost_window() else {
+        return false;
+    };
+    let Ok(frame) = window.frame() else {
+        return false;
+    };
+    let Ok(screens) = DisplayInfo::all() else {
+        return false;
+    };
+
+    screens.iter().any(|screen| {
+        frame.origin.x == screen.x as f64
+            && frame.origin.y == screen.y as f64
+            && frame.size.width == screen.width as f64
+            && frame.size.height == screen.height as f64
+    })
+}
+
 /// Raise/bring window to front on macOS
 /// This ensures the dragged window stays above the overlay
 pub fn raise_window(window: &AXUIElement) -> Result<(), String> {    
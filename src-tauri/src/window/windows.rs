@@ -1,6 +1,20 @@
+use windows::core::PWSTR;
 use windows::Win32::{
-    Foundation::HWND,
+    Foundation::{CloseHandle, HWND},
     Graphics::Gdi::{GetMonitorInfoW, MonitorFromWindow, MONITOR_DEFAULTTONEAREST, MONITORINFO},
+    System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    },
+    UI::{
+        Shell::{
+            SHQueryUserNotificationState, QUNS_BUSY, QUNS_PRESENTATION_MODE,
+            QUNS_RUNNING_D3D_FULL_SCREEN,
+        },
+        WindowsAndMessaging::{
+            GetClassNameW, GetForegroundWindow, GetWindowTextW, GetWindowThreadProcessId,
+        },
+    },
 };
 
 use crate::snapping::common::ScreenDimensions;
@@ -27,3 +41,70 @@ pub fn get_screen_dimensions_for_window(hwnd: HWND) -> Result<ScreenDimensions,
     })
 }
 
+/// Get the executable name of the foreground window's process, e.g. `Code.exe`
+pub fn get_foreground_executable() -> Option<String> {
+    let hwnd = unsafe { GetForegroundWindow() };
+    if hwnd.0.is_null() {
+        return None;
+    }
+
+    get_window_executable(hwnd)
+}
+
+/// Get the executable name of a window's process, e.g. `Code.exe`
+pub fn get_window_executable(hwnd: HWND) -> Option<String> {
+    unsafe {
+        let mut process_id = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut process_id));
+        if process_id == 0 {
+            return None;
+        }
+
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut length = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut length,
+        );
+        let _ = CloseHandle(process);
+        result.ok()?;
+
+        let path = String::from_utf16_lossy(&buffer[..length as usize]);
+        path.rsplit('\\').next().map(str::to_string)
+    }
+}
+
+/// Get the title of a window, empty if it has none
+pub fn get_window_title(hwnd: HWND) -> String {
+    let mut buffer = [0u16; 512];
+    let length = unsafe { GetWindowTextW(hwnd, &mut buffer) };
+
+    String::from_utf16_lossy(&buffer[..length.max(0) as usize])
+}
+
+/// Get the class a window was registered with, e.g. `Chrome_WidgetWin_1`
+pub fn get_window_class(hwnd: HWND) -> Option<String> {
+    let mut buffer = [0u16; 256];
+    let length = unsafe { GetClassNameW(hwnd, &mut buffer) };
+    if length <= 0 {
+        return None;
+    }
+
+    Some(String::from_utf16_lossy(&buffer[..length as usize]))
+}
+
+/// Whether a fullscreen game, video or presentation has the screen, as Windows
+/// decides when to hold back notifications
+pub fn is_fullscreen_app_focused() -> bool {
+    match unsafe { SHQueryUserNotificationState() } {
+        Ok(state) => {
+            state == QUNS_RUNNING_D3D_FULL_SCREEN
+                || state == QUNS_BUSY
+                || state == QUNS_PRESENTATION_MODE
+        }
+        Err(_) => false,
+    }
+}
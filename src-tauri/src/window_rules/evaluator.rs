@@ -0,0 +1,275 @@
+use regex::Regex;
+
+use crate::error::{CrossZonesError, Result};
+use crate::store::hotkey_options::app_matches;
+use crate::store::window_rules::{WindowMatch, WindowRule};
+
+/// What rules and exclusions match on, as each backend reads it from a window
+#[derive(Debug, Clone, Default)]
+pub struct WindowInfo {
+    /// Executable name on Windows, bundle id on macOS
+    pub app: Option<String>,
+    pub title: String,
+    /// Window class on Windows, subrole on macOS
+    pub class: Option<String>,
+}
+
+/// Criteria with the title pattern compiled, so windows are matched without compiling
+/// it again
+#[derive(Debug, Clone)]
+pub struct CompiledMatch {
+    criteria: WindowMatch,
+    /// `Err` if the pattern doesn't compile
+    title: Option<std::result::Result<Regex, regex::Error>>,
+}
+
+impl CompiledMatch {
+    pub fn new(criteria: &WindowMatch) -> Self {
+        Self {
+            criteria: criteria.clone(),
+            title: criteria.title.as_deref().map(Regex::new),
+        }
+    }
+
+    /// Whether it matches on the app, which is the slow part to read from a window
+    pub fn has_app(&self) -> bool {
+        self.criteria.app.is_some()
+    }
+}
+
+/// A rule with its criteria compiled
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    pub rule: WindowRule,
+    criteria: CompiledMatch,
+}
+
+impl CompiledRule {
+    pub fn new(rule: WindowRule) -> Self {
+        let criteria = CompiledMatch::new(&rule.criteria);
+        Self { rule, criteria }
+    }
+}
+
+/// Whether every criterion that is set matches the window. Without criteria every
+/// window matches, and a title pattern that doesn't compile matches none.
+pub fn window_matches(criteria: &CompiledMatch, window: &WindowInfo) -> bool {
+    let app = criteria.criteria.app.as_deref().is_none_or(|listed| {
+        window
+            .app
+            .as_deref()
+            .is_some_and(|app| app_matches(listed, app))
+    });
+    let class = criteria.criteria.class.as_deref().is_none_or(|listed| {
+        window
+            .class
+            .as_deref()
+            .is_some_and(|class| class.eq_ignore_ascii_case(listed.trim()))
+    });
+    let title = criteria.title.as_ref().is_none_or(|pattern| {
+        pattern
+            .as_ref()
+            .is_ok_and(|pattern| pattern.is_match(&window.title))
+    });
+
+    app && class && title
+}
+
+/// The first enabled rule for the window, if any
+pub fn matching_rule<'a>(rules: &'a [CompiledRule], window: &WindowInfo) -> Option<&'a WindowRule> {
+    rules
+        .iter()
+        .find(|rule| rule.rule.enabled && window_matches(&rule.criteria, window))
+        .map(|rule| &rule.rule)
+}
+
+/// Whether the window is on the exclusion list. Exclusions without criteria are
+/// skipped, as they would turn off snapping everywhere.
+pub fn is_excluded(exclusions: &[CompiledMatch], window: &WindowInfo) -> bool {
+    exclusions
+        .iter()
+        .any(|criteria| !criteria.criteria.is_empty() && window_matches(criteria, window))
+}
+
+fn validate_title(criteria: &WindowMatch, name: &str) -> Result<()> {
+    if let Some(pattern) = &criteria.title {
+        Regex::new(pattern).map_err(|e| {
+            CrossZonesError::Validation(format!("{} has an invalid title pattern: {}", name, e))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Reject rules whose title pattern isn't a valid regular expression
+pub fn validate_rules(rules: &[WindowRule]) -> Result<()> {
+    for rule in rules {
+        validate_title(&rule.criteria, &format!("Rule {}", rule.name))?;
+    }
+
+    Ok(())
+}
+
+/// Reject exclusions whose title pattern isn't a valid regular expression
+pub fn validate_exclusions(exclusions: &[WindowMatch]) -> Result<()> {
+    for (index, criteria) in exclusions.iter().enumerate() {
+        validate_title(criteria, &format!("Exclusion {}", index + 1))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::window_rules::RuleAction;
+
+    fn window(app: Option<&str>, title: &str, class: Option<&str>) -> WindowInfo {
+        WindowInfo {
+            app: app.map(str::to_string),
+            title: title.to_string(),
+            class: class.map(str::to_string),
+        }
+    }
+
+    fn criteria(app: Option<&str>, title: Option<&str>, class: Option<&str>) -> CompiledMatch {
+        CompiledMatch::new(&WindowMatch {
+            app: app.map(str::to_string),
+            title: title.map(str::to_string),
+            class: class.map(str::to_string),
+        })
+    }
+
+    fn rule(id: &str, enabled: bool, app: &str, action: RuleAction) -> CompiledRule {
+        CompiledRule::new(WindowRule {
+            id: id.to_string(),
+            name: id.to_string(),
+            enabled,
+            criteria: WindowMatch {
+                app: Some(app.to_string()),
+                ..Default::default()
+            },
+            action,
+        })
+    }
+
+    fn display(index: u32) -> RuleAction {
+        RuleAction::Display { index }
+    }
+
+    #[test]
+    fn app_matches_with_or_without_exe() {
+        let code = window(Some("Code.exe"), "", None);
+
+        assert!(window_matches(
+            &criteria(Some("Code.exe"), None, None),
+            &code
+        ));
+        assert!(window_matches(&criteria(Some("code"), None, None), &code));
+        assert!(window_matches(
+            &criteria(Some("code.exe"), None, None),
+            &window(Some("Code"), "", None)
+        ));
+        assert!(!window_matches(&criteria(Some("Cod"), None, None), &code));
+    }
+
+    #[test]
+    fn class_matches_ignoring_case() {
+        let chrome = window(None, "", Some("Chrome_WidgetWin_1"));
+
+        assert!(window_matches(
+            &criteria(None, None, Some("chrome_widgetwin_1")),
+            &chrome
+        ));
+        assert!(!window_matches(
+            &criteria(None, None, Some("Chrome_WidgetWin_0")),
+            &chrome
+        ));
+    }
+
+    #[test]
+    fn title_is_searched_with_the_pattern() {
+        let pattern = criteria(None, Some(r"- Visual Studio Code$"), None);
+
+        assert!(window_matches(
+            &pattern,
+            &window(None, "main.rs - Visual Studio Code", None)
+        ));
+        assert!(!window_matches(
+            &pattern,
+            &window(None, "Visual Studio Code - Settings", None)
+        ));
+    }
+
+    #[test]
+    fn criterion_without_window_field_does_not_match() {
+        let untitled = window(None, "", None);
+
+        assert!(!window_matches(
+            &criteria(Some("Code.exe"), None, None),
+            &untitled
+        ));
+        assert!(!window_matches(
+            &criteria(None, None, Some("Progman")),
+            &untitled
+        ));
+        assert!(window_matches(&criteria(None, None, None), &untitled));
+    }
+
+    #[test]
+    fn invalid_title_pattern_matches_nothing() {
+        let invalid = criteria(None, Some("["), None);
+
+        assert!(!window_matches(&invalid, &window(None, "[", None)));
+        assert!(!window_matches(&invalid, &window(None, "", None)));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rules = [
+            rule("chrome", true, "chrome.exe", display(0)),
+            rule("code", true, "Code.exe", display(1)),
+            rule("code again", true, "Code.exe", display(2)),
+        ];
+
+        let found = matching_rule(&rules, &window(Some("Code.exe"), "", None));
+        assert_eq!(found.map(|rule| rule.id.as_str()), Some("code"));
+        assert!(matching_rule(&rules, &window(Some("notepad.exe"), "", None)).is_none());
+    }
+
+    #[test]
+    fn disabled_rules_are_skipped() {
+        let rules = [
+            rule("off", false, "Code.exe", display(0)),
+            rule("on", true, "Code.exe", display(1)),
+        ];
+
+        let found = matching_rule(&rules, &window(Some("Code.exe"), "", None));
+        assert_eq!(found.map(|rule| rule.id.as_str()), Some("on"));
+    }
+
+    #[test]
+    fn ignore_stops_the_search() {
+        let rules = [
+            rule("ignore", true, "Code.exe", RuleAction::Ignore),
+            rule("place", true, "Code.exe", display(0)),
+        ];
+
+        let found = matching_rule(&rules, &window(Some("Code.exe"), "", None));
+        assert_eq!(found.map(|rule| &rule.action), Some(&RuleAction::Ignore));
+    }
+
+    #[test]
+    fn empty_exclusions_are_skipped() {
+        let anything = window(Some("Code.exe"), "main.rs", Some("Chrome_WidgetWin_1"));
+
+        assert!(!is_excluded(&[criteria(None, None, None)], &anything));
+        assert!(is_excluded(
+            &[
+                criteria(None, None, None),
+                criteria(Some("code"), None, None)
+            ],
+            &anything
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+pub fn start_window_watcher(_app_handle: &tauri::AppHandle) -> Result<(), String> {
+    // TODO: Watch for new windows once Linux snapping is implemented
+    Ok(())
+}
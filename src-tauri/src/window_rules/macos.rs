@@ -0,0 +1,94 @@
+use ::accessibility::AXUIElement;
+use accessibility::AXUIElementAttributes;
+use std::thread;
+use std::time::Duration;
+
+use super::evaluator::WindowInfo;
+use super::{is_excluded, placement_for, Placement};
+use crate::error::Result;
+use crate::snapping::macos::{place_window_with_element, snap_window_with_element};
+use crate::window::macos::{get_app_windows, get_bundle_id, get_window_pid};
+
+/// macOS has no window creation event for other apps short of an observer per app, so
+/// the app windows are listed this often to find new ones
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What rules and exclusions match a window on
+pub fn window_info(bundle_id: Option<String>, window: &AXUIElement) -> WindowInfo {
+    WindowInfo {
+        app: bundle_id,
+        title: window
+            .title()
+            .map(|title| title.to_string())
+            .unwrap_or_default(),
+        class: window.subrole().ok().map(|subrole| subrole.to_string()),
+    }
+}
+
+/// Whether a window is ours or on the exclusion list
+pub fn is_excluded_window(app: &tauri::AppHandle, window: &AXUIElement) -> bool {
+    let pid = get_window_pid(window);
+    if pid == Some(std::process::id() as i32) {
+        return true;
+    }
+
+    // The bundle id is looked up from the running apps, so it is left out unless needed
+    is_excluded(app, |with_app| {
+        let bundle_id = with_app.then(|| pid.and_then(get_bundle_id)).flatten();
+        window_info(bundle_id, window)
+    })
+}
+
+fn apply_rules(
+    app: &tauri::AppHandle,
+    bundle_id: Option<String>,
+    window: &AXUIElement,
+) -> Result<()> {
+    if is_excluded_window(app, window) {
+        return Ok(());
+    }
+
+    match placement_for(app, &window_info(bundle_id, window))? {
+        None => Ok(()),
+        Some(Placement::Action(action)) => {
+            snap_window_with_element(action, Some(app.clone()), window)
+        }
+        Some(placement) => {
+            place_window_with_element(window, |current, screen| placement.rect(current, screen))
+        }
+    }
+}
+
+fn run(app: tauri::AppHandle) {
+    // Windows listed the first time are open already and left where they are. Without
+    // accessibility access no windows are listed, so that waits until it is granted.
+    let mut seen: Option<Vec<AXUIElement>> = None;
+
+    loop {
+        if unsafe { accessibility_sys::AXIsProcessTrusted() } {
+            let windows = get_app_windows();
+            if let Some(seen) = &seen {
+                for (bundle_id, window) in &windows {
+                    if seen.contains(window) {
+                        continue;
+                    }
+                    if let Err(e) = apply_rules(&app, bundle_id.clone(), window) {
+                        eprintln!("Failed to apply window rules: {}", e);
+                    }
+                }
+            }
+            // Closed windows are forgotten
+            seen = Some(windows.into_iter().map(|(_, window)| window).collect());
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Place windows by the rules as they open
+pub fn start_window_watcher(app_handle: &tauri::AppHandle) -> std::result::Result<(), String> {
+    let app = app_handle.clone();
+    thread::spawn(move || run(app));
+
+    Ok(())
+}
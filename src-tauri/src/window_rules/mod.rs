@@ -0,0 +1,149 @@
+pub mod evaluator;
+
+#[cfg(target_os = "windows")]
+pub mod windows;
+
+#[cfg(target_os = "macos")]
+pub mod macos;
+
+#[cfg(target_os = "linux")]
+pub mod linux;
+
+#[cfg(target_os = "windows")]
+pub use windows::start_window_watcher;
+
+#[cfg(target_os = "macos")]
+pub use macos::start_window_watcher;
+
+#[cfg(target_os = "linux")]
+pub use linux::start_window_watcher;
+
+use display_info::DisplayInfo;
+
+use crate::error::{CrossZonesError, Result};
+use crate::snapping::action::LayoutAction;
+use crate::snapping::common::{fit_on_screen, zone_rect, ScreenDimensions};
+use crate::snapping::window_rect::WindowRect;
+use crate::store::cache;
+use crate::store::window_rules::{self, RuleAction, WindowMatch, WindowRule};
+use crate::store::zone_layouts::{self, Zone};
+use evaluator::{matching_rule, validate_exclusions, validate_rules, CompiledMatch, WindowInfo};
+
+/// Where a rule puts a window, with its layout or display looked up
+pub enum Placement {
+    /// Snapped like a hotkey would, so the window can be restored afterwards
+    Action(LayoutAction),
+    Zone(Zone),
+    Display(ScreenDimensions),
+}
+
+impl Placement {
+    /// The rect for a window at `current` on `screen`. Actions go through snapping
+    /// instead, for them this is where the window already is.
+    pub fn rect(&self, current: WindowRect, screen: ScreenDimensions) -> WindowRect {
+        match self {
+            Placement::Action(_) => current,
+            Placement::Zone(zone) => zone_rect(zone, screen),
+            Placement::Display(display) => fit_on_screen(current, *display),
+        }
+    }
+}
+
+/// Where the first rule matching a new window puts it, `None` to leave it alone
+pub fn placement_for(app: &tauri::AppHandle, window: &WindowInfo) -> Result<Option<Placement>> {
+    let rules = cache::window_rules(app)?;
+    let Some(rule) = matching_rule(&rules, window) else {
+        return Ok(None);
+    };
+
+    match &rule.action {
+        RuleAction::Zone {
+            layout_id,
+            zone_number,
+        } => {
+            let layout = zone_layouts::get_zone_layout(app.clone(), layout_id.clone())?
+                .ok_or_else(|| CrossZonesError::NotFound(format!("Layout {}", layout_id)))?;
+            let zone = layout
+                .zones
+                .iter()
+                .find(|zone| zone.number == *zone_number)
+                .cloned()
+                .ok_or_else(|| {
+                    CrossZonesError::NotFound(format!("Zone {} of {}", zone_number, layout.name))
+                })?;
+            Ok(Some(Placement::Zone(zone)))
+        }
+        RuleAction::Action { action } => Ok(Some(Placement::Action(action.clone()))),
+        RuleAction::Display { index } => {
+            let displays = DisplayInfo::all()
+                .map_err(|e| CrossZonesError::Platform(format!("Failed to get screens: {}", e)))?;
+            let display = displays
+                .get(*index as usize)
+                .ok_or_else(|| CrossZonesError::NotFound(format!("Display {}", index + 1)))?;
+            Ok(Some(Placement::Display(ScreenDimensions {
+                x: display.x,
+                y: display.y,
+                width: display.width as i32,
+                height: display.height as i32,
+            })))
+        }
+        RuleAction::Ignore => Ok(None),
+    }
+}
+
+/// Whether the window is on the exclusion list. Our own windows are checked by each
+/// backend, as only it knows which process a window belongs to.
+///
+/// This runs on every mouse button press, so `window` is only read if there are
+/// exclusions, and told whether they need the window's app.
+pub fn is_excluded(app: &tauri::AppHandle, window: impl FnOnce(bool) -> WindowInfo) -> bool {
+    let exclusions = match cache::window_exclusions(app) {
+        Ok(exclusions) => exclusions,
+        Err(e) => {
+            eprintln!("Failed to read window exclusions: {}", e);
+            return false;
+        }
+    };
+    if exclusions.is_empty() {
+        return false;
+    }
+
+    let with_app = exclusions.iter().any(CompiledMatch::has_app);
+    evaluator::is_excluded(&exclusions, &window(with_app))
+}
+
+#[tauri::command]
+pub fn get_window_rules(app: tauri::AppHandle) -> Result<Vec<WindowRule>> {
+    window_rules::window_rules(&app)
+}
+
+#[tauri::command]
+pub fn save_window_rules(app: tauri::AppHandle, rules: Vec<WindowRule>) -> Result<()> {
+    validate_rules(&rules)?;
+    window_rules::save_window_rules(&app, &rules)
+}
+
+#[tauri::command]
+pub fn get_window_exclusions(app: tauri::AppHandle) -> Result<Vec<WindowMatch>> {
+    window_rules::window_exclusions(&app)
+}
+
+#[tauri::command]
+pub fn save_window_exclusions(app: tauri::AppHandle, exclusions: Vec<WindowMatch>) -> Result<()> {
+    validate_exclusions(&exclusions)?;
+    window_rules::save_window_exclusions(&app, &exclusions)
+}
+
+/// Go back to the default exclusions, and return them
+#[tauri::command]
+pub fn reset_window_exclusions(app: tauri::AppHandle) -> Result<Vec<WindowMatch>> {
+    window_rules::reset_window_exclusions(&app)?;
+    window_rules::window_exclusions(&app)
+}
+
+/// Start placing new windows by the rules
+pub fn setup(app_handle: &tauri::AppHandle) {
+    if let Err(e) = start_window_watcher(app_handle) {
+        eprintln!("Failed to start the window watcher: {}", e);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::BTreeSet;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use windows::Win32::{
+    Foundation::HWND,
+    System::Threading::GetCurrentProcessId,
+    UI::{
+        Accessibility::{SetWinEventHook, HWINEVENTHOOK},
+        WindowsAndMessaging::{
+            DispatchMessageW, GetAncestor, GetMessageW, GetWindowLongW, GetWindowThreadProcessId,
+            IsWindowVisible, TranslateMessage, CHILDID_SELF, EVENT_OBJECT_DESTROY,
+            EVENT_OBJECT_SHOW, GA_ROOT, GWL_EXSTYLE, MSG, OBJID_WINDOW, WINEVENT_OUTOFCONTEXT,
+            WINEVENT_SKIPOWNPROCESS, WS_EX_TOOLWINDOW,
+        },
+    },
+};
+
+use super::evaluator::WindowInfo;
+use super::{is_excluded, placement_for, Placement};
+use crate::error::Result;
+use crate::snapping::windows::{
+    get_visible_windows, place_window_with_handle, snap_window_with_handle,
+};
+use crate::window::windows::{get_window_class, get_window_executable, get_window_title};
+
+/// Apps often move or resize their window right after showing it, rules wait for that
+const SETTLE_DELAY: Duration = Duration::from_millis(250);
+
+/// Windows shown for the first time, with when they were shown
+static EVENTS: OnceLock<Sender<(isize, Instant)>> = OnceLock::new();
+
+/// Windows that were shown before, so showing them again doesn't place them again.
+/// Windows are forgotten once destroyed, as handles get reused.
+static SEEN: Mutex<BTreeSet<isize>> = Mutex::new(BTreeSet::new());
+
+// Called on the hook thread for every window shown or destroyed in other processes
+extern "system" fn on_window_event(
+    _hook: HWINEVENTHOOK,
+    event: u32,
+    hwnd: HWND,
+    id_object: i32,
+    id_child: i32,
+    _thread_id: u32,
+    _time: u32,
+) {
+    // Only whole windows, not the controls, carets and cursors in them
+    if id_object != OBJID_WINDOW.0 || id_child != CHILDID_SELF as i32 || hwnd.0.is_null() {
+        return;
+    }
+
+    let handle = hwnd.0 as isize;
+    match event {
+        EVENT_OBJECT_DESTROY => {
+            SEEN.lock().unwrap().remove(&handle);
+        }
+        EVENT_OBJECT_SHOW => {
+            if unsafe { GetAncestor(hwnd, GA_ROOT) } != hwnd {
+                return;
+            }
+            // Tool windows such as tooltips and menus come and go all the time
+            let tool_window =
+                (unsafe { GetWindowLongW(hwnd, GWL_EXSTYLE) } as u32 & WS_EX_TOOLWINDOW.0) != 0;
+            if tool_window {
+                return;
+            }
+            if SEEN.lock().unwrap().insert(handle) {
+                if let Some(events) = EVENTS.get() {
+                    let _ = events.send((handle, Instant::now()));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// Out of context hooks are called from this thread's message loop
+fn run_hook() {
+    let hook = unsafe {
+        SetWinEventHook(
+            EVENT_OBJECT_DESTROY,
+            EVENT_OBJECT_SHOW,
+            None,
+            Some(on_window_event),
+            0,
+            0,
+            WINEVENT_OUTOFCONTEXT | WINEVENT_SKIPOWNPROCESS,
+        )
+    };
+    if hook.is_invalid() {
+        eprintln!("Failed to hook window events");
+        return;
+    }
+
+    let mut message = MSG::default();
+    while unsafe { GetMessageW(&mut message, None, 0, 0) }.as_bool() {
+        unsafe {
+            let _ = TranslateMessage(&message);
+            DispatchMessageW(&message);
+        }
+    }
+}
+
+/// What rules and exclusions match a window on
+pub fn window_info(hwnd: HWND) -> WindowInfo {
+    WindowInfo {
+        app: get_window_executable(hwnd),
+        title: get_window_title(hwnd),
+        class: get_window_class(hwnd),
+    }
+}
+
+/// Whether a window is ours or on the exclusion list. Controls are checked by the
+/// top-level window they are in, so clicks on the desktop icons count as the desktop.
+pub fn is_excluded_window(app: &tauri::AppHandle, hwnd: HWND) -> bool {
+    let hwnd = unsafe { GetAncestor(hwnd, GA_ROOT) };
+    if hwnd.0.is_null() {
+        return false;
+    }
+
+    let mut process_id = 0u32;
+    unsafe { GetWindowThreadProcessId(hwnd, Some(&mut process_id)) };
+    if process_id == unsafe { GetCurrentProcessId() } {
+        return true;
+    }
+
+    // Looking up the executable opens the process, so it is left out unless needed
+    is_excluded(app, |with_app| WindowInfo {
+        app: with_app.then(|| get_window_executable(hwnd)).flatten(),
+        title: get_window_title(hwnd),
+        class: get_window_class(hwnd),
+    })
+}
+
+fn apply_rules(app: &tauri::AppHandle, hwnd: HWND) -> Result<()> {
+    // Closed again while settling
+    if !unsafe { IsWindowVisible(hwnd) }.as_bool() {
+        return Ok(());
+    }
+    if is_excluded_window(app, hwnd) {
+        return Ok(());
+    }
+
+    match placement_for(app, &window_info(hwnd))? {
+        None => Ok(()),
+        Some(Placement::Action(action)) => snap_window_with_handle(action, Some(app.clone()), hwnd),
+        Some(placement) => {
+            place_window_with_handle(hwnd, |current, screen| placement.rect(current, screen))
+        }
+    }
+}
+
+fn run(app: tauri::AppHandle, events: Receiver<(isize, Instant)>) {
+    for (handle, shown) in events {
+        // Windows shown together settle together, rather than one delay after another
+        thread::sleep((shown + SETTLE_DELAY).saturating_duration_since(Instant::now()));
+        let hwnd = HWND(handle as *mut std::ffi::c_void);
+        if let Err(e) = apply_rules(&app, hwnd) {
+            eprintln!("Failed to apply window rules: {}", e);
+        }
+    }
+}
+
+/// Place windows by the rules as they are shown for the first time. Windows that are
+/// open already are left where they are.
+pub fn start_window_watcher(app_handle: &tauri::AppHandle) -> std::result::Result<(), String> {
+    let (sender, events) = mpsc::channel();
+    if EVENTS.set(sender).is_err() {
+        return Ok(());
+    }
+
+    SEEN.lock()
+        .unwrap()
+        .extend(get_visible_windows().iter().map(|hwnd| hwnd.0 as isize));
+
+    let app = app_handle.clone();
+    thread::spawn(move || run(app, events));
+    thread::spawn(run_hook);
+
+    Ok(())
+}
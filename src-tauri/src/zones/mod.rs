@@ -1 +1,4 @@
+pub mod split_tree;
+pub mod templates;
+pub mod validation;
 pub mod zone_layout_editor;
@@ -0,0 +1,374 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::error::CrossZonesError;
+use crate::store::zone_layouts::{Zone, ZoneLayout};
+
+/// Smallest fraction of its parent a child of a split may shrink to when moving a divider
+const MIN_RATIO: f64 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum SplitDirection {
+    /// Children are laid out left to right
+    Horizontal,
+    /// Children are laid out top to bottom
+    Vertical,
+}
+
+/// A node in a split-tree layout. Leaves become zones, splits divide their area between
+/// their children according to `ratios`, which always sum to 1 so the layout stays gap-free.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+#[ts(export)]
+pub enum SplitNode {
+    Zone {
+        id: String,
+    },
+    Split {
+        id: String,
+        direction: SplitDirection,
+        ratios: Vec<f64>,
+        children: Vec<SplitNode>,
+    },
+}
+
+impl Default for SplitNode {
+    fn default() -> Self {
+        SplitNode::Zone {
+            id: "zone-1".to_string(),
+        }
+    }
+}
+
+impl SplitNode {
+    pub fn id(&self) -> &str {
+        match self {
+            SplitNode::Zone { id } | SplitNode::Split { id, .. } => id,
+        }
+    }
+
+    /// Check that every split has a positive, finite ratio for each of its at least two
+    /// children, which `to_zones` and the divider edits rely on
+    pub fn validate(&self) -> Result<(), String> {
+        let SplitNode::Split {
+            id,
+            ratios,
+            children,
+            ..
+        } = self
+        else {
+            return Ok(());
+        };
+
+        if children.len() < 2 {
+            return Err(format!("Split {} must have at least 2 children", id));
+        }
+        if ratios.len() != children.len() {
+            return Err(format!(
+                "Split {} has {} ratios for {} children",
+                id,
+                ratios.len(),
+                children.len()
+            ));
+        }
+        if ratios
+            .iter()
+            .any(|ratio| !ratio.is_finite() || *ratio <= 0.0)
+        {
+            return Err(format!("Split {} has an invalid ratio", id));
+        }
+
+        children.iter().try_for_each(|child| child.validate())
+    }
+
+    /// Compile the tree into the flat zone list used for hit-testing and `ApplyZone`.
+    /// Zones are numbered in depth-first order, starting at 1.
+    pub fn to_zones(&self) -> Vec<Zone> {
+        let mut zones = Vec::new();
+        self.collect_zones(0.0, 0.0, 100.0, 100.0, &mut zones);
+        zones
+    }
+
+    fn collect_zones(&self, x: f64, y: f64, width: f64, height: f64, zones: &mut Vec<Zone>) {
+        match self {
+            SplitNode::Zone { id } => zones.push(Zone {
+                id: id.clone(),
+                x,
+                y,
+                width,
+                height,
+                number: zones.len() as u32 + 1,
+            }),
+            SplitNode::Split {
+                direction,
+                ratios,
+                children,
+                ..
+            } => {
+                let total: f64 = ratios.iter().sum();
+                let extent = match direction {
+                    SplitDirection::Horizontal => width,
+                    SplitDirection::Vertical => height,
+                };
+
+                let mut offset = 0.0;
+                for (index, child) in children.iter().enumerate() {
+                    // The last child always ends exactly at the parent's edge to avoid float drift
+                    let size = if index + 1 == children.len() {
+                        extent - offset
+                    } else {
+                        extent * ratios[index] / total
+                    };
+
+                    match direction {
+                        SplitDirection::Horizontal => {
+                            child.collect_zones(x + offset, y, size, height, zones)
+                        }
+                        SplitDirection::Vertical => {
+                            child.collect_zones(x, y + offset, width, size, zones)
+                        }
+                    }
+                    offset += size;
+                }
+            }
+        }
+    }
+
+    fn find_mut(&mut self, id: &str) -> Option<&mut SplitNode> {
+        if self.id() == id {
+            return Some(self);
+        }
+        match self {
+            SplitNode::Zone { .. } => None,
+            SplitNode::Split { children, .. } => {
+                children.iter_mut().find_map(|child| child.find_mut(id))
+            }
+        }
+    }
+
+    /// Find the split node that directly contains the node with the given id
+    fn find_parent_mut(&mut self, id: &str) -> Option<&mut SplitNode> {
+        let is_parent = match self {
+            SplitNode::Zone { .. } => return None,
+            SplitNode::Split { children, .. } => children.iter().any(|c| c.id() == id),
+        };
+        if is_parent {
+            return Some(self);
+        }
+        match self {
+            SplitNode::Split { children, .. } => children
+                .iter_mut()
+                .find_map(|child| child.find_parent_mut(id)),
+            SplitNode::Zone { .. } => None,
+        }
+    }
+
+    fn max_id_suffix(&self) -> u32 {
+        let own = self
+            .id()
+            .rsplit('-')
+            .next()
+            .and_then(|suffix| suffix.parse().ok())
+            .unwrap_or(0);
+        match self {
+            SplitNode::Zone { .. } => own,
+            SplitNode::Split { children, .. } => children
+                .iter()
+                .map(|child| child.max_id_suffix())
+                .fold(own, u32::max),
+        }
+    }
+
+    /// Split the zone `zone_id` into `parts` equal zones along `direction`
+    pub fn split(
+        &mut self,
+        zone_id: &str,
+        direction: SplitDirection,
+        parts: u32,
+    ) -> Result<(), String> {
+        if parts < 2 {
+            return Err("A zone must be split into at least 2 parts".to_string());
+        }
+        // Each part has to be large enough for the dividers next to it to move
+        if 1.0 / (parts as f64) < MIN_RATIO {
+            return Err(format!(
+                "A zone can be split into at most {} parts",
+                (1.0 / MIN_RATIO) as u32
+            ));
+        }
+
+        let mut next_id = self.max_id_suffix() + 1;
+        let node = self
+            .find_mut(zone_id)
+            .ok_or_else(|| format!("Zone {} not found", zone_id))?;
+        if !matches!(node, SplitNode::Zone { .. }) {
+            return Err(format!("{} is not a zone", zone_id));
+        }
+
+        // The original zone keeps its id as the first child, so it keeps its number too.
+        // Zones after it in the tree are numbered after the new ones, which moves
+        // `ApplyZone` hotkeys bound to their old numbers.
+        let mut children = vec![SplitNode::Zone {
+            id: zone_id.to_string(),
+        }];
+        for _ in 1..parts {
+            children.push(SplitNode::Zone {
+                id: format!("zone-{}", next_id),
+            });
+            next_id += 1;
+        }
+
+        *node = SplitNode::Split {
+            id: format!("split-{}", next_id),
+            direction,
+            ratios: vec![1.0 / parts as f64; parts as usize],
+            children,
+        };
+
+        Ok(())
+    }
+
+    /// Merge the zone `zone_id` with its adjacent sibling zone `other_id`. The merged zone
+    /// takes up the space of both and keeps the id of `zone_id`.
+    pub fn merge(&mut self, zone_id: &str, other_id: &str) -> Result<(), String> {
+        let parent = self
+            .find_parent_mut(zone_id)
+            .ok_or_else(|| format!("Zone {} not found", zone_id))?;
+
+        let SplitNode::Split {
+            ratios, children, ..
+        } = parent
+        else {
+            unreachable!("find_parent_mut only returns splits");
+        };
+
+        let index = children
+            .iter()
+            .position(|c| c.id() == zone_id)
+            .ok_or_else(|| format!("Zone {} not found", zone_id))?;
+        let other_index = children
+            .iter()
+            .position(|c| c.id() == other_id)
+            .ok_or_else(|| format!("Zone {} is not a sibling of {}", other_id, zone_id))?;
+
+        if index.abs_diff(other_index) != 1 {
+            return Err(format!("Zones {} and {} are not adjacent", zone_id, other_id));
+        }
+        if !matches!(children[other_index], SplitNode::Zone { .. })
+            || !matches!(children[index], SplitNode::Zone { .. })
+        {
+            return Err("Only zones can be merged".to_string());
+        }
+
+        ratios[index] += ratios[other_index];
+        ratios.remove(other_index);
+        children.remove(other_index);
+
+        // A split with a single child is just that child
+        if children.len() == 1 {
+            *parent = children.remove(0);
+        }
+
+        Ok(())
+    }
+
+    /// Move the divider between child `divider` and `divider + 1` of split `split_id`
+    /// to `position`, a fraction (0–1) of the split's extent. Only the two neighbouring
+    /// children are resized, and each keeps at least `MIN_RATIO` of the split.
+    pub fn move_divider(
+        &mut self,
+        split_id: &str,
+        divider: usize,
+        position: f64,
+    ) -> Result<(), String> {
+        if !position.is_finite() {
+            return Err(format!("Invalid divider position {}", position));
+        }
+
+        let node = self
+            .find_mut(split_id)
+            .ok_or_else(|| format!("Split {} not found", split_id))?;
+
+        let SplitNode::Split { ratios, .. } = node else {
+            return Err(format!("{} is not a split", split_id));
+        };
+        if divider + 1 >= ratios.len() {
+            return Err(format!("Split {} has no divider {}", split_id, divider));
+        }
+
+        let total: f64 = ratios.iter().sum();
+        for ratio in ratios.iter_mut() {
+            *ratio /= total;
+        }
+
+        // Evenly split zones can end up a hair under twice the minimum after normalizing
+        let pair = ratios[divider] + ratios[divider + 1];
+        if pair + f64::EPSILON < 2.0 * MIN_RATIO {
+            return Err(format!(
+                "Zones next to divider {} of split {} are too small to resize",
+                divider, split_id
+            ));
+        }
+
+        // Not `clamp`, which panics when that drift puts the lower bound above the upper
+        let start: f64 = ratios[..divider].iter().sum();
+        let offset = (position - start).max(MIN_RATIO).min(pair - MIN_RATIO);
+
+        ratios[divider] = offset;
+        ratios[divider + 1] = pair - offset;
+
+        Ok(())
+    }
+}
+
+fn with_split_tree(
+    mut layout: ZoneLayout,
+    edit: impl FnOnce(&mut SplitNode) -> Result<(), String>,
+) -> Result<ZoneLayout, String> {
+    let mut tree = match layout.split_tree.take() {
+        Some(tree) => tree,
+        None if layout.zones.is_empty() => SplitNode::default(),
+        None => return Err("Layout is not a split-tree layout".to_string()),
+    };
+
+    tree.validate()?;
+    edit(&mut tree)?;
+
+    layout.zones = tree.to_zones();
+    layout.split_tree = Some(tree);
+    Ok(layout)
+}
+
+#[tauri::command]
+pub fn split_layout_zone(
+    layout: ZoneLayout,
+    zone_id: String,
+    direction: SplitDirection,
+    parts: u32,
+) -> Result<ZoneLayout, CrossZonesError> {
+    with_split_tree(layout, |tree| tree.split(&zone_id, direction, parts))
+        .map_err(CrossZonesError::Validation)
+}
+
+#[tauri::command]
+pub fn merge_layout_zones(
+    layout: ZoneLayout,
+    zone_id: String,
+    other_zone_id: String,
+) -> Result<ZoneLayout, CrossZonesError> {
+    with_split_tree(layout, |tree| tree.merge(&zone_id, &other_zone_id))
+        .map_err(CrossZonesError::Validation)
+}
+
+#[tauri::command]
+pub fn move_layout_divider(
+    layout: ZoneLayout,
+    split_id: String,
+    divider: usize,
+    position: f64,
+) -> Result<ZoneLayout, CrossZonesError> {
+    with_split_tree(layout, |tree| tree.move_divider(&split_id, divider, position))
+        .map_err(CrossZonesError::Validation)
+}
@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::error::CrossZonesError;
+use crate::store::zone_layouts::{generate_id, Zone, ZoneLayout};
+
+/// Upper bound on rows/columns a template may generate
+const MAX_DIVISIONS: u32 = 16;
+
+/// Width of the centre zone in the focus template, as a fraction of the screen
+const FOCUS_CENTER_RATIO: f64 = 0.6;
+
+/// Parametric layout templates. All zones are in percentages (0–100), numbered from 1.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+#[ts(export)]
+pub enum ZoneLayoutTemplate {
+    /// `count` equal columns, numbered left to right
+    Columns { count: u32 },
+    /// `count` equal rows, numbered top to bottom
+    Rows { count: u32 },
+    /// `columns` × `rows` equal cells, numbered row by row
+    Grid { columns: u32, rows: u32 },
+    /// A wide centre zone (number 1) with the remaining zones stacked in the side columns
+    PriorityGrid { count: u32 },
+    /// A centre zone (number 1) flanked by a left (2) and right (3) zone
+    Focus,
+    /// Left, centre and right columns, where the centre takes `center_ratio` of the width
+    ThreeColumn { center_ratio: f64 },
+}
+
+impl ZoneLayoutTemplate {
+    pub fn name(&self) -> String {
+        match self {
+            ZoneLayoutTemplate::Columns { count } => format!("{} Columns", count),
+            ZoneLayoutTemplate::Rows { count } => format!("{} Rows", count),
+            ZoneLayoutTemplate::Grid { columns, rows } => format!("{}×{} Grid", columns, rows),
+            ZoneLayoutTemplate::PriorityGrid { count } => format!("Priority Grid ({})", count),
+            ZoneLayoutTemplate::Focus => "Focus".to_string(),
+            ZoneLayoutTemplate::ThreeColumn { center_ratio } => {
+                format!("Three Columns ({}% centre)", (center_ratio * 100.0).round())
+            }
+        }
+    }
+
+    /// Generate the zones for this template
+    pub fn zones(&self) -> Result<Vec<Zone>, String> {
+        let mut zones = Vec::new();
+
+        match *self {
+            ZoneLayoutTemplate::Columns { count } => {
+                check_divisions("count", count)?;
+                push_grid(&mut zones, 0.0, 0.0, 100.0, 100.0, count, 1);
+            }
+            ZoneLayoutTemplate::Rows { count } => {
+                check_divisions("count", count)?;
+                push_grid(&mut zones, 0.0, 0.0, 100.0, 100.0, 1, count);
+            }
+            ZoneLayoutTemplate::Grid { columns, rows } => {
+                check_divisions("columns", columns)?;
+                check_divisions("rows", rows)?;
+                push_grid(&mut zones, 0.0, 0.0, 100.0, 100.0, columns, rows);
+            }
+            ZoneLayoutTemplate::PriorityGrid { count } => {
+                check_divisions("count", count)?;
+                if count < 3 {
+                    push_grid(&mut zones, 0.0, 0.0, 100.0, 100.0, count, 1);
+                } else {
+                    push_zone(&mut zones, 25.0, 0.0, 50.0, 100.0);
+                    let remaining = count - 1;
+                    let left = remaining.div_ceil(2);
+                    push_grid(&mut zones, 0.0, 0.0, 25.0, 100.0, 1, left);
+                    push_grid(&mut zones, 75.0, 0.0, 25.0, 100.0, 1, remaining - left);
+                }
+            }
+            ZoneLayoutTemplate::Focus => {
+                let center = FOCUS_CENTER_RATIO * 100.0;
+                let side = (100.0 - center) / 2.0;
+                push_zone(&mut zones, side, 0.0, center, 100.0);
+                push_zone(&mut zones, 0.0, 0.0, side, 100.0);
+                push_zone(&mut zones, side + center, 0.0, side, 100.0);
+            }
+            ZoneLayoutTemplate::ThreeColumn { center_ratio } => {
+                if !(0.1..=0.9).contains(&center_ratio) {
+                    return Err(format!(
+                        "center_ratio must be between 0.1 and 0.9, got {}",
+                        center_ratio
+                    ));
+                }
+                let center = center_ratio * 100.0;
+                let side = (100.0 - center) / 2.0;
+                push_zone(&mut zones, 0.0, 0.0, side, 100.0);
+                push_zone(&mut zones, side, 0.0, center, 100.0);
+                push_zone(&mut zones, side + center, 0.0, side, 100.0);
+            }
+        }
+
+        Ok(zones)
+    }
+}
+
+fn check_divisions(field: &str, value: u32) -> Result<(), String> {
+    if value == 0 || value > MAX_DIVISIONS {
+        return Err(format!(
+            "{} must be between 1 and {}, got {}",
+            field, MAX_DIVISIONS, value
+        ));
+    }
+    Ok(())
+}
+
+fn push_zone(zones: &mut Vec<Zone>, x: f64, y: f64, width: f64, height: f64) {
+    zones.push(Zone {
+        id: generate_id("zone"),
+        x,
+        y,
+        width,
+        height,
+        number: zones.len() as u32 + 1,
+    });
+}
+
+/// Push a `columns` × `rows` grid of equal cells covering the given area, row by row
+fn push_grid(
+    zones: &mut Vec<Zone>,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    columns: u32,
+    rows: u32,
+) {
+    let cell_width = width / columns as f64;
+    let cell_height = height / rows as f64;
+
+    for row in 0..rows {
+        for column in 0..columns {
+            push_zone(
+                zones,
+                x + column as f64 * cell_width,
+                y + row as f64 * cell_height,
+                cell_width,
+                cell_height,
+            );
+        }
+    }
+}
+
+#[tauri::command]
+pub fn generate_zone_layout(
+    template: ZoneLayoutTemplate,
+    name: Option<String>,
+) -> Result<ZoneLayout, CrossZonesError> {
+    Ok(ZoneLayout {
+        id: generate_id("layout"),
+        name: name.unwrap_or_else(|| template.name()),
+        zones: template.zones().map_err(CrossZonesError::Validation)?,
+        screen_width: None,
+        screen_height: None,
+        split_tree: None,
+    })
+}
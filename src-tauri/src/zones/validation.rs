@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use ts_rs::TS;
+
+use crate::store::zone_layouts::{self, Zone, ZoneLayout};
+
+/// Tolerance (in percent) below which overlaps, gaps and out-of-bounds edges are ignored
+const EPSILON: f64 = 0.01;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "kebab-case")]
+#[ts(export)]
+pub enum IssueSeverity {
+    /// The layout cannot be saved
+    Error,
+    /// The layout works, but probably not as intended
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+#[ts(export)]
+pub enum ZoneLayoutIssueKind {
+    /// Zone has a zero, negative or non-finite width or height
+    InvalidSize { zone_id: String },
+    /// Zone extends outside the 0–100 range
+    OutOfBounds { zone_id: String },
+    /// More than one zone shares an id
+    DuplicateId { zone_id: String },
+    /// More than one zone shares a number, so `ApplyZone` can only reach one of them
+    DuplicateNumber { number: u32, zone_ids: Vec<String> },
+    /// Two zones overlap by `area` percent of the screen
+    Overlap { zone_ids: Vec<String>, area: f64 },
+    /// Part of the screen is not covered by any zone
+    Gap { uncovered: f64 },
+    /// Zone numbers that are skipped, so hotkeys bound to them do nothing
+    MissingNumbers { numbers: Vec<u32> },
+    /// Zone cannot be reached, either because its number is 0 or because earlier zones
+    /// cover it entirely so drag-and-drop never hits it
+    UnreachableZone { zone_id: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ZoneLayoutIssue {
+    pub severity: IssueSeverity,
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub kind: ZoneLayoutIssueKind,
+    pub message: String,
+}
+
+impl ZoneLayoutIssue {
+    fn error(kind: ZoneLayoutIssueKind, message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            kind,
+            message,
+        }
+    }
+
+    fn warning(kind: ZoneLayoutIssueKind, message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            kind,
+            message,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+fn has_valid_size(zone: &Zone) -> bool {
+    zone.width.is_finite() && zone.height.is_finite() && zone.width > 0.0 && zone.height > 0.0
+}
+
+fn intersection_area(a: &Zone, b: &Zone) -> f64 {
+    let width = (a.x + a.width).min(b.x + b.width) - a.x.max(b.x);
+    let height = (a.y + a.height).min(b.y + b.height) - a.y.max(b.y);
+    if width <= 0.0 || height <= 0.0 {
+        0.0
+    } else {
+        width * height
+    }
+}
+
+fn contains(outer: &Zone, inner: &Zone) -> bool {
+    inner.x >= outer.x - EPSILON
+        && inner.y >= outer.y - EPSILON
+        && inner.x + inner.width <= outer.x + outer.width + EPSILON
+        && inner.y + inner.height <= outer.y + outer.height + EPSILON
+}
+
+/// Area (in percent of the screen) covered by the union of the given zones, clipped to 0–100
+fn covered_area(zones: &[&Zone]) -> f64 {
+    let clip = |v: f64| v.clamp(0.0, 100.0);
+    let mut xs: Vec<f64> = zones
+        .iter()
+        .flat_map(|z| [clip(z.x), clip(z.x + z.width)])
+        .collect();
+    let mut ys: Vec<f64> = zones
+        .iter()
+        .flat_map(|z| [clip(z.y), clip(z.y + z.height)])
+        .collect();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+    ys.sort_by(|a, b| a.total_cmp(b));
+    ys.dedup();
+
+    let mut area = 0.0;
+    for x in xs.windows(2) {
+        for y in ys.windows(2) {
+            let (cx, cy) = ((x[0] + x[1]) / 2.0, (y[0] + y[1]) / 2.0);
+            let covered = zones.iter().any(|z| {
+                cx >= z.x && cx <= z.x + z.width && cy >= z.y && cy <= z.y + z.height
+            });
+            if covered {
+                area += (x[1] - x[0]) * (y[1] - y[0]) / 100.0;
+            }
+        }
+    }
+    area
+}
+
+/// Validate a zone layout, returning every issue found. Layouts with any
+/// `IssueSeverity::Error` issue are rejected by `save_zone_layout`.
+pub fn validate_layout(layout: &ZoneLayout) -> Vec<ZoneLayoutIssue> {
+    let mut issues = Vec::new();
+
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    let mut numbers: HashMap<u32, Vec<String>> = HashMap::new();
+
+    for zone in &layout.zones {
+        *ids.entry(zone.id.as_str()).or_default() += 1;
+        numbers.entry(zone.number).or_default().push(zone.id.clone());
+
+        if !has_valid_size(zone) {
+            issues.push(ZoneLayoutIssue::error(
+                ZoneLayoutIssueKind::InvalidSize {
+                    zone_id: zone.id.clone(),
+                },
+                format!(
+                    "Zone {} has an invalid size ({} × {})",
+                    zone.number, zone.width, zone.height
+                ),
+            ));
+            continue;
+        }
+
+        if !zone.x.is_finite()
+            || !zone.y.is_finite()
+            || zone.x < -EPSILON
+            || zone.y < -EPSILON
+            || zone.x + zone.width > 100.0 + EPSILON
+            || zone.y + zone.height > 100.0 + EPSILON
+        {
+            issues.push(ZoneLayoutIssue::error(
+                ZoneLayoutIssueKind::OutOfBounds {
+                    zone_id: zone.id.clone(),
+                },
+                format!("Zone {} extends outside the screen", zone.number),
+            ));
+        }
+    }
+
+    let mut duplicate_ids: Vec<&str> = ids
+        .iter()
+        .filter(|(_, count)| **count > 1)
+        .map(|(id, _)| *id)
+        .collect();
+    duplicate_ids.sort();
+    for id in duplicate_ids {
+        issues.push(ZoneLayoutIssue::error(
+            ZoneLayoutIssueKind::DuplicateId {
+                zone_id: id.to_string(),
+            },
+            format!("Zone id {} is used more than once", id),
+        ));
+    }
+
+    let mut duplicate_numbers: Vec<(&u32, &Vec<String>)> =
+        numbers.iter().filter(|(_, ids)| ids.len() > 1).collect();
+    duplicate_numbers.sort_by_key(|(number, _)| **number);
+    for (number, zone_ids) in duplicate_numbers {
+        issues.push(ZoneLayoutIssue::error(
+            ZoneLayoutIssueKind::DuplicateNumber {
+                number: *number,
+                zone_ids: zone_ids.clone(),
+            },
+            format!("Zone number {} is used by {} zones", number, zone_ids.len()),
+        ));
+    }
+
+    // Geometry checks only make sense for zones with a usable size
+    let zones: Vec<&Zone> = layout.zones.iter().filter(|z| has_valid_size(z)).collect();
+
+    for (i, a) in zones.iter().enumerate() {
+        for b in &zones[i + 1..] {
+            let area = intersection_area(a, b) / 100.0;
+            if area > EPSILON {
+                issues.push(ZoneLayoutIssue::warning(
+                    ZoneLayoutIssueKind::Overlap {
+                        zone_ids: vec![a.id.clone(), b.id.clone()],
+                        area,
+                    },
+                    format!(
+                        "Zones {} and {} overlap by {:.1}% of the screen",
+                        a.number, b.number, area
+                    ),
+                ));
+            }
+        }
+    }
+
+    let uncovered = 100.0 - covered_area(&zones);
+    if uncovered > EPSILON {
+        issues.push(ZoneLayoutIssue::warning(
+            ZoneLayoutIssueKind::Gap { uncovered },
+            format!("{:.1}% of the screen is not covered by any zone", uncovered),
+        ));
+    }
+
+    let used: BTreeSet<u32> = layout.zones.iter().map(|z| z.number).collect();
+    if let Some(&max) = used.last() {
+        let missing: Vec<u32> = (1..max).filter(|n| !used.contains(n)).collect();
+        if !missing.is_empty() {
+            issues.push(ZoneLayoutIssue::warning(
+                ZoneLayoutIssueKind::MissingNumbers {
+                    numbers: missing.clone(),
+                },
+                format!(
+                    "Zone numbers {} are skipped",
+                    missing
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ));
+        }
+    }
+
+    for (i, zone) in zones.iter().enumerate() {
+        // Drag-and-drop picks the first zone containing the cursor
+        let hidden = zones[..i].iter().any(|earlier| contains(earlier, zone));
+        if zone.number == 0 || hidden {
+            issues.push(ZoneLayoutIssue::warning(
+                ZoneLayoutIssueKind::UnreachableZone {
+                    zone_id: zone.id.clone(),
+                },
+                if hidden {
+                    format!("Zone {} is entirely covered by an earlier zone", zone.number)
+                } else {
+                    "Zone number 0 cannot be bound to a hotkey".to_string()
+                },
+            ));
+        }
+    }
+
+    issues
+}
+
+#[tauri::command]
+pub fn validate_zone_layout(layout: ZoneLayout) -> Vec<ZoneLayoutIssue> {
+    validate_layout(&layout)
+}
+
+/// Report issues in layouts that were stored before validation was enforced on save
+pub fn validate_stored_layouts(app_handle: &tauri::AppHandle) {
+    let layouts = match zone_layouts::get_all_zone_layouts(app_handle.clone()) {
+        Ok(layouts) => layouts,
+        Err(e) => {
+            eprintln!("Failed to load zone layouts for validation: {}", e);
+            return;
+        }
+    };
+
+    for layout in layouts {
+        for issue in validate_layout(&layout) {
+            eprintln!(
+                "Zone layout \"{}\" ({:?}): {}",
+                layout.name, issue.severity, issue.message
+            );
+        }
+    }
+}
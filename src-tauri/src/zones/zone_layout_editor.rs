@@ -1,3 +1,4 @@
+use crate::error::{CrossZonesError, Result};
 use crate::store::zone_layouts::Zone;
 use display_info::DisplayInfo;
 use serde::{Deserialize, Serialize};
@@ -21,8 +22,9 @@ pub struct ScreenInfo {
 }
 
 #[tauri::command]
-pub fn get_all_screens() -> Result<Vec<ScreenInfo>, String> {
-    let screens = DisplayInfo::all().map_err(|e| format!("Failed to get screens: {}", e))?;
+pub fn get_all_screens() -> Result<Vec<ScreenInfo>> {
+    let screens = DisplayInfo::all()
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to get screens: {}", e)))?;
 
     Ok(screens
         .into_iter()
@@ -43,8 +45,9 @@ pub fn create_zone_editor_windows(
     layout_id: String,
     layout_name: String,
     zones: Vec<Zone>,
-) -> Result<(), String> {
-    let screens = DisplayInfo::all().map_err(|e| format!("Failed to get screens: {}", e))?;
+) -> Result<()> {
+    let screens = DisplayInfo::all()
+        .map_err(|e| CrossZonesError::Platform(format!("Failed to get screens: {}", e)))?;
 
     // Destroy existing editor windows first
     destroy_all_editor_windows(app.clone())?;
@@ -113,19 +116,19 @@ pub fn create_zone_editor_windows(
 }
 
 #[tauri::command]
-pub fn destroy_all_editor_windows(app: tauri::AppHandle) -> Result<(), String> {
+pub fn destroy_all_editor_windows(app: tauri::AppHandle) -> Result<()> {
     let mut windows = EDITOR_WINDOWS.lock().unwrap();
 
     for (_label, window) in windows.drain() {
         let _ = window.destroy();
     }
 
-    let _ = app.emit("editor-closed", &serde_json::json!({})).unwrap();
+    let _ = app.emit("editor-closed", &serde_json::json!({}));
     Ok(())
 }
 
 #[tauri::command]
-pub fn store_editor_zones(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<(), String> {
+pub fn store_editor_zones(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<()> {
     let mut stored_zones = EDITOR_ZONES.lock().unwrap();
     *stored_zones = zones.clone();
 
@@ -136,7 +139,7 @@ pub fn store_editor_zones(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<(),
 }
 
 #[tauri::command]
-pub fn close_editor_windows(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<(), String> {
+pub fn close_editor_windows(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<()> {
     // Store zones before closing
     store_editor_zones(app.clone(), zones)?;
 
@@ -145,7 +148,7 @@ pub fn close_editor_windows(app: tauri::AppHandle, zones: Vec<Zone>) -> Result<(
 }
 
 #[tauri::command]
-pub fn get_editor_zones() -> Result<Vec<Zone>, String> {
+pub fn get_editor_zones() -> Result<Vec<Zone>> {
     let zones = EDITOR_ZONES.lock().unwrap();
     Ok(zones.clone())
 }
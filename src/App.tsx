@@ -9,6 +9,7 @@ import { ThemeProvider } from "@/lib/theme-provider";
 import { MainView } from "@/screens/MainView";
 import { FullscreenZoneEditor } from "@/features/ZoneLayouts/ZoneEditor/FullscreenZoneEditor";
 import { ZoneOverlay } from "@/features/ZoneLayouts/ZoneOverlay/ZoneOverlay";
+import { ChordHint } from "@/features/ChordHint/ChordHint";
 import { useState } from "react";
 import { Toaster } from "sonner";
 
@@ -34,6 +35,9 @@ function App() {
           <Route path="/zone-overlay">
             <ZoneOverlay />
           </Route>
+          <Route path="/chord-hint">
+            <ChordHint />
+          </Route>
           <Route path="/">
             <Layout 
               activeTab={activeTab} 
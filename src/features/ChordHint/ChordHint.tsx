@@ -0,0 +1,57 @@
+import { useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import type { ChordHint as ChordHintData } from '../../../src-tauri/bindings/ChordHint';
+
+export function ChordHint() {
+  const [hint, setHint] = useState<ChordHintData | null>(null);
+
+  useEffect(() => {
+    const unlisten = listen<ChordHintData>('chord-hint', (event) => {
+      setHint(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // The first hint is passed in the URL, before the page listens for events
+  useEffect(() => {
+    const dataParam = new URLSearchParams(window.location.search).get('data');
+    if (!dataParam) {
+      return;
+    }
+
+    try {
+      setHint(JSON.parse(atob(dataParam.replace(/-/g, '+').replace(/_/g, '/'))));
+    } catch (error) {
+      console.error('Failed to parse chord hint from URL:', error);
+    }
+  }, []);
+
+  if (!hint) {
+    return null;
+  }
+
+  return (
+    <div className="w-screen h-screen flex items-end justify-center bg-transparent p-2">
+      <div className="w-full rounded-lg border bg-background/95 shadow-lg p-3 space-y-2 select-none">
+        <div className="text-sm font-medium">
+          {hint.pressed.length > 0 ? hint.pressed.join(' ') : 'Leader'} …
+        </div>
+        <ul className="space-y-1">
+          {hint.continuations.map((continuation) => (
+            <li key={continuation.key} className="flex items-center gap-2 text-sm">
+              <kbd className="min-w-6 rounded border bg-muted px-1.5 text-center font-mono">
+                {continuation.label}
+              </kbd>
+              <span className="text-muted-foreground truncate">
+                {continuation.actions.length > 0 ? continuation.actions.join(', ') : '…'}
+              </span>
+            </li>
+          ))}
+        </ul>
+      </div>
+    </div>
+  );
+}
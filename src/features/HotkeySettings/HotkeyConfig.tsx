@@ -1,9 +1,11 @@
-import { X } from "lucide-react";
+import { useState } from "react";
+import { SlidersHorizontal, X } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
 import { WindowSnapIcon } from "@/components/WindowSnapIcon/WindowSnapIcon";
 import { type HotkeyConfig } from "./types";
 import { getShortcutMapping } from "./keyMapping";
+import { HotkeyOptionsDialog } from "./HotkeyOptionsDialog";
 
 interface HotkeyConfigProps {
   config: HotkeyConfig;
@@ -16,6 +18,7 @@ interface HotkeyConfigProps {
 
 export const HotkeyConfigComponent: React.FC<HotkeyConfigProps> = ({ config, index, recording, setRecording, onShortcutClear, handleKeyDown }) => {
   const canRecord = config.layoutAction !== null || config.zoneNumber !== undefined;
+  const [optionsOpen, setOptionsOpen] = useState(false);
 
   return (
     <div key={index} className="flex items-center gap-2">
@@ -43,6 +46,16 @@ export const HotkeyConfigComponent: React.FC<HotkeyConfigProps> = ({ config, ind
             onKeyDown={canRecord ? handleKeyDown : undefined}
           />
         </div>
+        <Button
+          variant="outline"
+          disabled={!config.shortcut}
+          size="icon"
+          className={`h-7 w-7 ${!config.shortcut ? 'invisible' : ''}`}
+          title="Options"
+          onClick={() => setOptionsOpen(true)}
+        >
+          <SlidersHorizontal className="h-3 w-3" />
+        </Button>
         <Button
           variant="outline"
           disabled={!config.shortcut}
@@ -53,6 +66,13 @@ export const HotkeyConfigComponent: React.FC<HotkeyConfigProps> = ({ config, ind
           <X className="h-3 w-3" />
         </Button>
       </div>
+      {config.shortcut && (
+        <HotkeyOptionsDialog
+          shortcut={config.shortcut}
+          open={optionsOpen}
+          onOpenChange={setOptionsOpen}
+        />
+      )}
     </div>
   );
 };
\ No newline at end of file
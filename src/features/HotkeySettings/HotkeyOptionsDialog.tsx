@@ -0,0 +1,116 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { showError } from '@/lib/toast';
+import { getShortcutMapping } from './keyMapping';
+import { HotkeyOptions } from '../../../src-tauri/bindings/HotkeyOptions';
+import { ScopeMode } from '../../../src-tauri/bindings/ScopeMode';
+import { WindowTarget } from '../../../src-tauri/bindings/WindowTarget';
+
+interface HotkeyOptionsDialogProps {
+  shortcut: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+type ScopeChoice = ScopeMode | 'everywhere';
+
+export const HotkeyOptionsDialog: React.FC<HotkeyOptionsDialogProps> = ({ shortcut, open, onOpenChange }) => {
+  const [scopeMode, setScopeMode] = useState<ScopeChoice>('everywhere');
+  const [apps, setApps] = useState('');
+  const [target, setTarget] = useState<WindowTarget>('focused');
+
+  useEffect(() => {
+    if (!open) return;
+    invoke<HotkeyOptions>('get_hotkey_options', { shortcut })
+      .then((options) => {
+        setScopeMode(options.scope?.mode ?? 'everywhere');
+        setApps(options.scope?.apps.join(', ') ?? '');
+        setTarget(options.target);
+      })
+      .catch(error => showError('Failed to load hotkey options', error));
+  }, [open, shortcut]);
+
+  const handleSave = async () => {
+    const appList = apps.split(',').map(app => app.trim()).filter(app => app.length > 0);
+    const options: HotkeyOptions = {
+      scope: scopeMode === 'everywhere' ? null : { mode: scopeMode, apps: appList },
+      target,
+    };
+    try {
+      await invoke('set_hotkey_options', { shortcut, options });
+      onOpenChange(false);
+    } catch (error) {
+      showError('Failed to save hotkey options', error);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent>
+        <DialogHeader>
+          <DialogTitle>{getShortcutMapping(shortcut)}</DialogTitle>
+          <DialogDescription>
+            Choose the window the hotkey acts on, and limit it to some applications by
+            executable name on Windows (Code.exe) or bundle id on macOS
+            (com.microsoft.VSCode). Elsewhere the keys go to the application as if the
+            hotkey wasn't there.
+          </DialogDescription>
+        </DialogHeader>
+        <div className="flex flex-col gap-2">
+          <Select value={target} onValueChange={(value) => setTarget(value as WindowTarget)}>
+            <SelectTrigger className="w-[220px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="focused">Focused window</SelectItem>
+              <SelectItem value="under-cursor">Window under the cursor</SelectItem>
+              <SelectItem value="cursor-display">Top window on the cursor's display</SelectItem>
+            </SelectContent>
+          </Select>
+          <Select value={scopeMode} onValueChange={(value) => setScopeMode(value as ScopeChoice)}>
+            <SelectTrigger className="w-[220px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="everywhere">In every application</SelectItem>
+              <SelectItem value="include">Only in these applications</SelectItem>
+              <SelectItem value="exclude">Not in these applications</SelectItem>
+            </SelectContent>
+          </Select>
+          {scopeMode !== 'everywhere' && (
+            <Input
+              placeholder="Code.exe, com.microsoft.VSCode"
+              value={apps}
+              onChange={(e) => setApps(e.target.value)}
+            />
+          )}
+        </div>
+        <DialogFooter>
+          <Button variant="outline" onClick={() => onOpenChange(false)}>
+            Cancel
+          </Button>
+          <Button onClick={handleSave}>
+            Save
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+};
@@ -0,0 +1,120 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Button } from '@/components/ui/button';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { showError, showSuccess } from '@/lib/toast';
+import { HotkeyPreset } from '../../../src-tauri/bindings/HotkeyPreset';
+import { HotkeyConflict } from '../../../src-tauri/bindings/HotkeyConflict';
+import { HotkeyReport } from '../../../src-tauri/bindings/HotkeyReport';
+import { PresetMode } from '../../../src-tauri/bindings/PresetMode';
+
+interface HotkeyPresetSelectProps {
+  onApplied: () => void;
+}
+
+export const HotkeyPresetSelect: React.FC<HotkeyPresetSelectProps> = ({ onApplied }) => {
+  const [presets, setPresets] = useState<HotkeyPreset[]>([]);
+  const [presetId, setPresetId] = useState<string>('');
+  const [conflicts, setConflicts] = useState<HotkeyConflict[] | null>(null);
+
+  useEffect(() => {
+    invoke<HotkeyPreset[]>('get_hotkey_presets')
+      .then(setPresets)
+      .catch(error => showError('Failed to load hotkey presets', error));
+  }, []);
+
+  const applyPreset = async (mode: PresetMode) => {
+    setConflicts(null);
+    try {
+      const report = await invoke<HotkeyReport>('apply_hotkey_preset', { presetId, mode });
+      showSuccess(`Bound ${report.active.length} hotkeys`);
+      if (report.inactive.length > 0) {
+        showError('Some preset hotkeys were skipped', undefined, {
+          description: report.inactive.map(conflict => conflict.message).join('\n'),
+        });
+      }
+      onApplied();
+    } catch (error) {
+      showError('Failed to apply hotkey preset', error);
+    }
+  };
+
+  // Only ask how to apply the preset when it clashes with the current hotkeys
+  const handleApply = async () => {
+    try {
+      const found = await invoke<HotkeyConflict[]>('check_hotkey_preset', {
+        presetId,
+        mode: 'merge',
+      });
+      if (found.length === 0) {
+        applyPreset('merge');
+      } else {
+        setConflicts(found);
+      }
+    } catch (error) {
+      showError('Failed to check hotkey preset', error);
+    }
+  };
+
+  const selectedPreset = presets.find(preset => preset.id === presetId);
+
+  return (
+    <div className="flex items-center gap-2">
+      <span className="text-sm font-medium select-none">Preset</span>
+      <Select value={presetId} onValueChange={setPresetId}>
+        <SelectTrigger className="w-[180px]" title={selectedPreset?.description}>
+          <SelectValue placeholder="Select preset" />
+        </SelectTrigger>
+        <SelectContent>
+          {presets.map(preset => (
+            <SelectItem key={preset.id} value={preset.id}>{preset.name}</SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+      <Button variant="outline" size="sm" disabled={!presetId} onClick={handleApply}>
+        Apply
+      </Button>
+      <Dialog open={conflicts !== null} onOpenChange={(open) => !open && setConflicts(null)}>
+        <DialogContent>
+          <DialogHeader>
+            <DialogTitle>Apply {selectedPreset?.name} Preset</DialogTitle>
+            <DialogDescription>
+              Some of its shortcuts clash with your hotkeys. Merge keeps your hotkeys and
+              skips these shortcuts, replace removes all your hotkeys first.
+            </DialogDescription>
+          </DialogHeader>
+          <ul className="text-sm list-disc pl-5">
+            {conflicts?.map(conflict => (
+              <li key={conflict.shortcut}>{conflict.message}</li>
+            ))}
+          </ul>
+          <DialogFooter>
+            <Button variant="outline" onClick={() => setConflicts(null)}>
+              Cancel
+            </Button>
+            <Button variant="outline" onClick={() => applyPreset('merge')}>
+              Merge
+            </Button>
+            <Button variant="destructive" onClick={() => applyPreset('replace')}>
+              Replace
+            </Button>
+          </DialogFooter>
+        </DialogContent>
+      </Dialog>
+    </div>
+  );
+};
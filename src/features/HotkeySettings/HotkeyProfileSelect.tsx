@@ -0,0 +1,99 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { Plus } from 'lucide-react';
+import { showError } from '@/lib/toast';
+import { HotkeyProfile } from '../../../src-tauri/bindings/HotkeyProfile';
+
+export const HotkeyProfileSelect: React.FC = () => {
+  const [profiles, setProfiles] = useState<HotkeyProfile[]>([]);
+  const [activeProfileId, setActiveProfileId] = useState<string>('');
+  const [newProfileName, setNewProfileName] = useState<string | null>(null);
+
+  const loadProfiles = async () => {
+    try {
+      setProfiles(await invoke<HotkeyProfile[]>('get_hotkey_profiles'));
+      setActiveProfileId(await invoke<string>('get_active_hotkey_profile_id'));
+    } catch (error) {
+      showError('Failed to load hotkey profiles', error);
+    }
+  };
+
+  useEffect(() => {
+    loadProfiles();
+
+    // The profile can also be switched from the tray or a hotkey
+    const unlisten = listen<string>('hotkey-profile-changed', (event) => {
+      setActiveProfileId(event.payload);
+    });
+    return () => {
+      unlisten.then((unlistenFn) => unlistenFn());
+    };
+  }, []);
+
+  const handleProfileChange = async (profileId: string) => {
+    try {
+      await invoke('activate_hotkey_profile', { profileId });
+    } catch (error) {
+      showError('Failed to switch hotkey profile', error);
+    }
+  };
+
+  const handleCreate = async () => {
+    if (!newProfileName?.trim()) {
+      setNewProfileName(null);
+      return;
+    }
+    try {
+      await invoke<HotkeyProfile>('create_hotkey_profile', { name: newProfileName });
+      setNewProfileName(null);
+      loadProfiles();
+    } catch (error) {
+      showError('Failed to create hotkey profile', error);
+    }
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      <span className="text-sm font-medium select-none">Profile</span>
+      <Select value={activeProfileId} onValueChange={handleProfileChange}>
+        <SelectTrigger className="w-[180px]">
+          <SelectValue placeholder="Select profile" />
+        </SelectTrigger>
+        <SelectContent>
+          {profiles.map(profile => (
+            <SelectItem key={profile.id} value={profile.id}>{profile.name}</SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+      {newProfileName === null ? (
+        <Button variant="outline" size="sm" onClick={() => setNewProfileName('')}>
+          <Plus className="h-4 w-4" />
+          New Profile
+        </Button>
+      ) : (
+        <Input
+          autoFocus
+          className="w-[180px] h-8"
+          placeholder="Profile name"
+          value={newProfileName}
+          onChange={(e) => setNewProfileName(e.target.value)}
+          onBlur={() => setNewProfileName(null)}
+          onKeyDown={(e) => {
+            if (e.key === 'Enter') handleCreate();
+            if (e.key === 'Escape') setNewProfileName(null);
+          }}
+        />
+      )}
+    </div>
+  );
+};
@@ -1,12 +1,17 @@
 import React, { useEffect, useState } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { AVAILABLE_HOTKEYS, HOTKEY_GROUPS } from './constants';
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import { Skeleton } from "@/components/ui/skeleton";
 import { HotkeyConfigComponent } from './HotkeyConfig';
+import { HotkeyProfileSelect } from './HotkeyProfileSelect';
+import { HotkeyPresetSelect } from './HotkeyPresetSelect';
 import { type HotkeyConfig, serializeLayoutAction, createActionPayload } from './types';
-import { handleHotkeyKeyDown } from './useHotkeyRecording';
+import { cancelShortcutRecording, handleHotkeyKeyDown, recordNextShortcut } from './useHotkeyRecording';
 import { showError } from '@/lib/toast';
+import { HotkeyReport } from '../../../src-tauri/bindings/HotkeyReport';
+import { HotkeyBinding } from '../../../src-tauri/bindings/HotkeyBinding';
 
 interface HotkeyGroupProps {
   title: string;
@@ -24,8 +29,42 @@ const HotkeyGroup: React.FC<HotkeyGroupProps> = ({
   spanFullWidth = false
 }) => {
   const [recording, setRecording] = useState<string | null>(null);
+  // Set when the backend can't listen to the keyboard, e.g. without permission
+  const [recordInWebview, setRecordInWebview] = useState(false);
+
+  useEffect(() => {
+    if (!recording || recordInWebview) {
+      return;
+    }
+    const config = configs.find(c => getRecordingKey(c) === recording);
+    let active = true;
+
+    recordNextShortcut()
+      .then((shortcut) => {
+        if (!active) return;
+        if (shortcut && config) {
+          onShortcutChange(config, shortcut);
+        }
+        setRecording(null);
+      })
+      .catch((error) => {
+        console.error('Failed to record shortcut, recording in the webview instead', error);
+        if (active) setRecordInWebview(true);
+      });
+
+    return () => {
+      active = false;
+      cancelShortcutRecording();
+    };
+  }, [recording, recordInWebview]);
 
   const handleKeyDown = (e: React.KeyboardEvent, config: HotkeyConfig) => {
+    if (!recordInWebview) {
+      // The backend records the keys, the input only stops them from typing
+      e.preventDefault();
+      if (e.key === 'Escape') setRecording(null);
+      return;
+    }
     handleHotkeyKeyDown(
       e,
       (shortcut: string) => {
@@ -93,8 +132,10 @@ export const HotkeySettings: React.FC = () => {
         return;
       }
 
-      // Unregister existing hotkey for this action
-      await invoke('unregister_hotkey_action', { action: actionPayload });
+      // Unregister the shortcut this action had so far
+      if (config.shortcut) {
+        await invoke('unregister_hotkey', { shortcut: config.shortcut });
+      }
       
       // Register new hotkey
       await invoke('register_hotkey_action', { shortcut, action: actionPayload });
@@ -107,13 +148,9 @@ export const HotkeySettings: React.FC = () => {
 
   const handleShortcutClear = async (config: HotkeyConfig) => {
     try {
-      // Create the action payload with consistent structure
-      const actionPayload = createActionPayload(config);
-      if (!actionPayload) {
-        return;
+      if (config.shortcut) {
+        await invoke('unregister_hotkey', { shortcut: config.shortcut });
       }
-
-      await invoke('unregister_hotkey_action', { action: actionPayload });
       updateHotkey(config, '');
     } catch (error) {
       showError('Failed to clear hotkey', error);
@@ -125,29 +162,49 @@ export const HotkeySettings: React.FC = () => {
     configs: hotkeys.filter(hotkey => hotkey.group === group)
   }));
 
+  const loadHotkeys = async () => {
+    try {
+      const existingHotkeys = await invoke<HotkeyBinding[]>('get_all_hotkeys');
+
+      const mappedHotkeys = AVAILABLE_HOTKEYS.map(hotkey => {
+        const serializedAction = serializeLayoutAction(hotkey.layoutAction, hotkey.zoneNumber);
+        // Macros have no entry in this list, only single actions are shown
+        const existingHotkey = existingHotkeys.find(
+          h => h.actions.length === 1 && JSON.stringify(h.actions[0]) === serializedAction
+        );
+        return {
+          ...hotkey,
+          shortcut: existingHotkey?.shortcut || ''
+        };
+      });
+
+      setHotkeys(mappedHotkeys);
+      setIsLoading(false);
+    } catch (error) {
+      showError('Failed to load hotkeys', error);
+      setIsLoading(false);
+    }
+  };
+
   useEffect(() => {
-    const loadHotkeys = async () => {
-      try {
-        const existingHotkeys = await invoke('get_all_hotkeys') as [string, string][];
-
-        const mappedHotkeys = AVAILABLE_HOTKEYS.map(hotkey => {
-          const serializedAction = serializeLayoutAction(hotkey.layoutAction, hotkey.zoneNumber);
-          const existingHotkey = existingHotkeys.find(h => h[1] === serializedAction);
-          return {
-            ...hotkey,
-            shortcut: existingHotkey?.[0] || ''
-          };
-        });
+    loadHotkeys();
 
-        setHotkeys(mappedHotkeys);
-        setIsLoading(false);
-      } catch (error) {
-        showError('Failed to load hotkeys', error);
-        setIsLoading(false);
+    // Another profile brings its own shortcuts
+    const unlisten = listen('hotkey-profile-changed', () => {
+      loadHotkeys();
+    });
+    // Sent when other hotkeys became inactive, so the same report isn't shown twice
+    const unlistenReport = listen<HotkeyReport>('hotkey-report', ({ payload: report }) => {
+      if (report.inactive.length > 0) {
+        showError('Some hotkeys are inactive', undefined, {
+          description: report.inactive.map(conflict => conflict.message).join('\n'),
+        });
       }
+    });
+    return () => {
+      unlisten.then((unlistenFn) => unlistenFn());
+      unlistenReport.then((unlistenFn) => unlistenFn());
     };
-
-    loadHotkeys();
   }, []);
 
   if (isLoading) {
@@ -177,6 +234,10 @@ export const HotkeySettings: React.FC = () => {
 
   return (
     <div className="grid grid-cols-2 gap-4">
+      <div className="col-span-2 flex items-center gap-6">
+        <HotkeyProfileSelect />
+        <HotkeyPresetSelect onApplied={loadHotkeys} />
+      </div>
       {groupedHotkeys.map(({ title, configs }) => (
         <HotkeyGroup
           key={title}
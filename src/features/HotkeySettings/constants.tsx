@@ -43,6 +43,7 @@ export const AVAILABLE_HOTKEYS: HotkeyConfig[] = [
   { name: 'Move Down', shortcut: '', layoutAction: LayoutAction.MoveDown, group: HOTKEY_GROUPS.MOVEMENT_CONTROLS },
   { name: 'Next Display', shortcut: '', layoutAction: LayoutAction.NextDisplay, group: HOTKEY_GROUPS.MOVEMENT_CONTROLS },
   { name: 'Previous Display', shortcut: '', layoutAction: LayoutAction.PreviousDisplay, group: HOTKEY_GROUPS.MOVEMENT_CONTROLS },
+  { name: 'Window Mode', shortcut: '', layoutAction: LayoutAction.WindowMode, group: HOTKEY_GROUPS.MOVEMENT_CONTROLS },
 
   // Third Controls
   { name: 'First Third', shortcut: '', layoutAction: LayoutAction.FirstThird, group: HOTKEY_GROUPS.THIRD_CONTROLS },
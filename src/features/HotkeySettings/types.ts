@@ -8,7 +8,7 @@ export interface HotkeyConfig {
   zoneNumber?: number; // For ApplyZone actions
 }
 
-// ActionPayload structure matching the Rust ActionPayload
+// Matches the serialized Rust LayoutAction (src-tauri/bindings/LayoutAction.ts)
 export interface ActionPayload {
   action: string;
   zone_number?: number;
@@ -32,7 +32,7 @@ export function createActionPayload(config: HotkeyConfig): ActionPayload | null
 }
 
 // Helper function to serialize a layout action to JSON string (as stored in backend)
-// The backend now uses ActionPayload format: {"action": "left-half"} or {"action": "apply-zone", "zone_number": 1}
+// The backend stores the serialized LayoutAction: {"action": "left-half"} or {"action": "apply-zone", "zone_number": 1}
 export function serializeLayoutAction(action: LayoutAction | null, zoneNumber?: number): string {
   if (zoneNumber !== undefined) {
     return JSON.stringify({ action: 'apply-zone', zone_number: zoneNumber });
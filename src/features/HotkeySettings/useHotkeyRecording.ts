@@ -1,3 +1,25 @@
+import { invoke } from '@tauri-apps/api/core';
+
+/** How long the backend waits for a shortcut before giving up */
+const RECORDING_TIMEOUT_MS = 10000;
+
+/**
+ * Records the next shortcut pressed anywhere through the backend, which also sees
+ * combos the webview misses, such as ones with the Windows/Super key
+ *
+ * @returns the shortcut, or null on Escape, timeout or cancellation
+ */
+export function recordNextShortcut(): Promise<string | null> {
+  return invoke<string | null>('record_next_shortcut', { timeoutMs: RECORDING_TIMEOUT_MS });
+}
+
+/**
+ * Ends a recording started with recordNextShortcut, which then resolves to null
+ */
+export function cancelShortcutRecording(): Promise<void> {
+  return invoke('cancel_shortcut_recording');
+}
+
 /**
  * Shared utility function for handling hotkey recording keydown events
  * Extracts modifiers and non-modifier keys from keyboard events
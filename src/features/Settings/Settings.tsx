@@ -19,11 +19,13 @@ interface SettingDefinition {
 enum SettingCategory {
   Application = 'Application',
   Zones = 'Zones',
+  Hotkeys = 'Hotkeys',
 }
 
 const CATEGORY_LABELS: Record<SettingCategory, string> = {
   [SettingCategory.Application]: 'Application',
   [SettingCategory.Zones]: 'Zones',
+  [SettingCategory.Hotkeys]: 'Hotkeys',
 };
 
 const SETTINGS_SCHEMA: SettingDefinition[] = [
@@ -69,6 +71,34 @@ const SETTINGS_SCHEMA: SettingDefinition[] = [
     type: 'number',
     category: SettingCategory.Zones
   },
+  {
+    key: 'show_chord_hints',
+    label: 'Show chord hints',
+    description: 'Show the keys that can follow after pressing the leader key',
+    type: 'boolean',
+    category: SettingCategory.Hotkeys
+  },
+  {
+    key: 'hold_to_preview',
+    label: 'Hold hotkeys to preview',
+    description: 'While a hotkey is held, show where the window will go. Release to apply, press Esc to cancel',
+    type: 'boolean',
+    category: SettingCategory.Hotkeys
+  },
+  {
+    key: 'key_repeat',
+    label: 'Repeat held hotkeys',
+    description: 'Keep moving or resizing the window while a move, nudge, smaller or larger hotkey is held',
+    type: 'boolean',
+    category: SettingCategory.Hotkeys
+  },
+  {
+    key: 'suppress_hotkeys_in_fullscreen',
+    label: 'Pause hotkeys in fullscreen apps',
+    description: 'Let games and other fullscreen apps have the keys of your hotkeys',
+    type: 'boolean',
+    category: SettingCategory.Hotkeys
+  },
 ];
 
 export const Settings: React.FC = () => {
@@ -79,8 +109,7 @@ export const Settings: React.FC = () => {
 
   const loadSettings = async () => {
     try {
-      const settings = await getSettings();
-      setSettings(Object.fromEntries(settings.map(([key, value]) => [key, value as any])) as SettingsType);
+      setSettings(await getSettings());
       setLoading(false);
     } catch (error) {
       showError('Failed to load settings', error);
@@ -0,0 +1,103 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Plus, Trash2 } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card';
+import { Input } from '@/components/ui/input';
+import { showError, showSuccess } from '@/lib/toast';
+import { WindowMatch } from '../../../src-tauri/bindings/WindowMatch';
+
+// Empty fields match any window
+const criterion = (value: string) => (value.trim() === '' ? null : value);
+
+export const WindowExclusions: React.FC = () => {
+  const [exclusions, setExclusions] = useState<WindowMatch[]>([]);
+
+  useEffect(() => {
+    invoke<WindowMatch[]>('get_window_exclusions')
+      .then(setExclusions)
+      .catch(error => showError('Failed to load window exclusions', error));
+  }, []);
+
+  const updateExclusion = (index: number, changes: Partial<WindowMatch>) => {
+    setExclusions(exclusions.map((exclusion, i) => (i === index ? { ...exclusion, ...changes } : exclusion)));
+  };
+
+  const handleSave = async () => {
+    try {
+      await invoke('save_window_exclusions', { exclusions });
+      showSuccess('Window exclusions saved');
+    } catch (error) {
+      showError('Failed to save window exclusions', error);
+    }
+  };
+
+  const handleReset = async () => {
+    try {
+      setExclusions(await invoke<WindowMatch[]>('reset_window_exclusions'));
+    } catch (error) {
+      showError('Failed to restore window exclusions', error);
+    }
+  };
+
+  return (
+    <div className="flex justify-center w-full">
+      <Card className="max-w-2xl w-full">
+        <CardHeader>
+          <CardTitle>Excluded Windows</CardTitle>
+          <CardDescription>
+            Hotkeys, zone dragging and window rules leave these windows alone, as well as
+            CrossZones' own windows. An entry needs every field that is filled in to match.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-2">
+          {exclusions.map((exclusion, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <Input
+                placeholder="App, e.g. Code.exe"
+                value={exclusion.app ?? ''}
+                onChange={(e) => updateExclusion(index, { app: criterion(e.target.value) })}
+              />
+              <Input
+                placeholder="Title pattern"
+                value={exclusion.title ?? ''}
+                onChange={(e) => updateExclusion(index, { title: criterion(e.target.value) })}
+              />
+              <Input
+                placeholder="Window class"
+                value={exclusion.class ?? ''}
+                onChange={(e) => updateExclusion(index, { class: criterion(e.target.value) })}
+              />
+              <Button
+                variant="ghost"
+                size="icon"
+                title="Delete exclusion"
+                onClick={() => setExclusions(exclusions.filter((_, i) => i !== index))}
+              >
+                <Trash2 className="h-4 w-4 text-muted-foreground" />
+              </Button>
+            </div>
+          ))}
+          <div className="flex items-center justify-between pt-2">
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => setExclusions([...exclusions, { app: null, title: null, class: null }])}
+            >
+              <Plus className="h-4 w-4 mr-2" />
+              Add exclusion
+            </Button>
+            <div className="flex items-center gap-2">
+              <Button variant="outline" size="sm" onClick={handleReset}>
+                Restore defaults
+              </Button>
+              <Button size="sm" onClick={handleSave}>
+                Save
+              </Button>
+            </div>
+          </div>
+        </CardContent>
+      </Card>
+    </div>
+  );
+};
@@ -0,0 +1,245 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Plus, Trash2 } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card';
+import { Checkbox } from '@/components/ui/checkbox';
+import { Input } from '@/components/ui/input';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import { generateId } from '@/lib/utils';
+import { showError, showSuccess } from '@/lib/toast';
+import type { ZoneLayout } from '@/types/zoneLayout';
+import { AVAILABLE_HOTKEYS } from '@/features/HotkeySettings/constants';
+import { WindowRule } from '../../../src-tauri/bindings/WindowRule';
+import { RuleAction } from '../../../src-tauri/bindings/RuleAction';
+import { LayoutAction } from '../../../src-tauri/bindings/LayoutAction';
+
+type RuleActionType = RuleAction['type'];
+
+const ACTION_OPTIONS = AVAILABLE_HOTKEYS.filter(hotkey => hotkey.layoutAction !== null);
+
+// Empty fields match any window
+const criterion = (value: string) => (value.trim() === '' ? null : value);
+
+function defaultAction(type: RuleActionType, layouts: ZoneLayout[]): RuleAction {
+  switch (type) {
+    case 'zone':
+      return { type, layout_id: layouts[0]?.id ?? '', zone_number: 1 };
+    case 'action':
+      return { type, action: { action: 'maximize' } };
+    case 'display':
+      return { type, index: 0 };
+    case 'ignore':
+      return { type };
+  }
+}
+
+export const WindowRules: React.FC = () => {
+  const [rules, setRules] = useState<WindowRule[]>([]);
+  const [layouts, setLayouts] = useState<ZoneLayout[]>([]);
+  const [screenCount, setScreenCount] = useState(1);
+
+  useEffect(() => {
+    invoke<WindowRule[]>('get_window_rules')
+      .then(setRules)
+      .catch(error => showError('Failed to load window rules', error));
+    invoke<ZoneLayout[]>('get_all_zone_layouts')
+      .then(setLayouts)
+      .catch(error => showError('Failed to load zone layouts', error));
+    invoke<unknown[]>('get_all_screens')
+      .then(screens => setScreenCount(Math.max(screens.length, 1)))
+      .catch(error => showError('Failed to get screens', error));
+  }, []);
+
+  const updateRule = (id: string, changes: Partial<WindowRule>) => {
+    setRules(rules.map(rule => (rule.id === id ? { ...rule, ...changes } : rule)));
+  };
+
+  const addRule = () => {
+    setRules([
+      ...rules,
+      {
+        id: generateId('rule'),
+        name: `Rule ${rules.length + 1}`,
+        enabled: true,
+        app: null,
+        title: null,
+        class: null,
+        action: defaultAction('action', layouts),
+      },
+    ]);
+  };
+
+  const handleSave = async () => {
+    try {
+      await invoke('save_window_rules', { rules });
+      showSuccess('Window rules saved');
+    } catch (error) {
+      showError('Failed to save window rules', error);
+    }
+  };
+
+  const renderActionTarget = (rule: WindowRule) => {
+    const action = rule.action;
+    switch (action.type) {
+      case 'zone': {
+        const layout = layouts.find(layout => layout.id === action.layout_id);
+        return (
+          <>
+            <Select
+              value={action.layout_id}
+              onValueChange={(layout_id) => updateRule(rule.id, { action: { ...action, layout_id } })}
+            >
+              <SelectTrigger className="w-[160px]">
+                <SelectValue placeholder="Layout" />
+              </SelectTrigger>
+              <SelectContent>
+                {layouts.map(layout => (
+                  <SelectItem key={layout.id} value={layout.id}>{layout.name}</SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+            <Select
+              value={String(action.zone_number)}
+              onValueChange={(zone) => updateRule(rule.id, { action: { ...action, zone_number: Number(zone) } })}
+            >
+              <SelectTrigger className="w-[110px]">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {(layout?.zones ?? []).map(zone => (
+                  <SelectItem key={zone.id} value={String(zone.number)}>Zone {zone.number}</SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </>
+        );
+      }
+      case 'action':
+        return (
+          <Select
+            value={action.action.action}
+            onValueChange={(value) => updateRule(rule.id, {
+              action: { type: 'action', action: { action: value } as LayoutAction },
+            })}
+          >
+            <SelectTrigger className="w-[180px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {ACTION_OPTIONS.map(option => (
+                <SelectItem key={option.layoutAction} value={option.layoutAction!}>{option.name}</SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        );
+      case 'display':
+        return (
+          <Select
+            value={String(action.index)}
+            onValueChange={(index) => updateRule(rule.id, { action: { type: 'display', index: Number(index) } })}
+          >
+            <SelectTrigger className="w-[140px]">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {Array.from({ length: Math.max(screenCount, action.index + 1) }, (_, index) => (
+                <SelectItem key={index} value={String(index)}>Display {index + 1}</SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        );
+      case 'ignore':
+        return null;
+    }
+  };
+
+  return (
+    <div className="flex justify-center w-full">
+      <Card className="max-w-2xl w-full">
+        <CardHeader>
+          <CardTitle>Window Rules</CardTitle>
+          <CardDescription>
+            Place windows as they open. The first enabled rule whose app, title pattern and
+            class all match is used, empty fields match any window.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          {rules.map(rule => (
+            <div key={rule.id} className="space-y-2 border-b pb-4">
+              <div className="flex items-center gap-2">
+                <Checkbox
+                  checked={rule.enabled}
+                  onCheckedChange={(checked) => updateRule(rule.id, { enabled: checked === true })}
+                />
+                <Input
+                  value={rule.name}
+                  onChange={(e) => updateRule(rule.id, { name: e.target.value })}
+                />
+                <Button
+                  variant="ghost"
+                  size="icon"
+                  title="Delete rule"
+                  onClick={() => setRules(rules.filter(other => other.id !== rule.id))}
+                >
+                  <Trash2 className="h-4 w-4 text-muted-foreground" />
+                </Button>
+              </div>
+              <div className="flex items-center gap-2">
+                <Input
+                  placeholder="App, e.g. Code.exe"
+                  value={rule.app ?? ''}
+                  onChange={(e) => updateRule(rule.id, { app: criterion(e.target.value) })}
+                />
+                <Input
+                  placeholder="Title pattern"
+                  value={rule.title ?? ''}
+                  onChange={(e) => updateRule(rule.id, { title: criterion(e.target.value) })}
+                />
+                <Input
+                  placeholder="Window class"
+                  value={rule.class ?? ''}
+                  onChange={(e) => updateRule(rule.id, { class: criterion(e.target.value) })}
+                />
+              </div>
+              <div className="flex items-center gap-2">
+                <Select
+                  value={rule.action.type}
+                  onValueChange={(type) => updateRule(rule.id, {
+                    action: defaultAction(type as RuleActionType, layouts),
+                  })}
+                >
+                  <SelectTrigger className="w-[150px]">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="zone">Zone</SelectItem>
+                    <SelectItem value="action">Action</SelectItem>
+                    <SelectItem value="display">Display</SelectItem>
+                    <SelectItem value="ignore">Leave alone</SelectItem>
+                  </SelectContent>
+                </Select>
+                {renderActionTarget(rule)}
+              </div>
+            </div>
+          ))}
+          <div className="flex items-center justify-between">
+            <Button variant="outline" size="sm" onClick={addRule}>
+              <Plus className="h-4 w-4 mr-2" />
+              Add rule
+            </Button>
+            <Button size="sm" onClick={handleSave}>
+              Save
+            </Button>
+          </div>
+        </CardContent>
+      </Card>
+    </div>
+  );
+};
@@ -6,6 +6,7 @@ import { X } from 'lucide-react';
 import { getShortcutMapping } from '@/features/HotkeySettings/keyMapping';
 import { handleHotkeyKeyDown } from '@/features/HotkeySettings/useHotkeyRecording';
 import { showError } from '@/lib/toast';
+import { HotkeyBinding } from '../../../src-tauri/bindings/HotkeyBinding';
 
 interface ZoneHotkeyInputProps {
   layoutId: string;
@@ -23,13 +24,15 @@ export const ZoneHotkeyInput: React.FC<ZoneHotkeyInputProps> = ({ layoutId, onRe
 
   const loadHotkey = async () => {
     try {
-      const allHotkeys = await invoke<[string, string][]>('get_all_hotkeys');
+      const allHotkeys = await invoke<HotkeyBinding[]>('get_all_hotkeys');
       const actionPayload = getActionPayload();
       const actionJson = JSON.stringify(actionPayload);
       
       // Find hotkey that matches this layout action
-      const hotkey = allHotkeys.find(([_shortcut, action]) => action === actionJson);
-      setShortcut(hotkey?.[0] || '');
+      const hotkey = allHotkeys.find(
+        ({ actions }) => actions.length === 1 && JSON.stringify(actions[0]) === actionJson
+      );
+      setShortcut(hotkey?.shortcut || '');
     } catch (error) {
       showError('Failed to load hotkey', error);
     }
@@ -47,8 +50,10 @@ export const ZoneHotkeyInput: React.FC<ZoneHotkeyInputProps> = ({ layoutId, onRe
           // Use new ActionPayload structure
           const action = getActionPayload();
           
-          // Unregister existing hotkey for this action if any
-          await invoke('unregister_hotkey_action', { action });
+          // Unregister the shortcut this layout had so far, if any
+          if (shortcut) {
+            await invoke('unregister_hotkey', { shortcut });
+          }
           
           // Register new hotkey
           await invoke('register_hotkey_action', { shortcut: newShortcut, action });
@@ -68,8 +73,9 @@ export const ZoneHotkeyInput: React.FC<ZoneHotkeyInputProps> = ({ layoutId, onRe
 
   const handleClear = async () => {
     try {
-      const action = getActionPayload();
-      await invoke('unregister_hotkey_action', { action });
+      if (shortcut) {
+        await invoke('unregister_hotkey', { shortcut });
+      }
       setShortcut('');
       onRefresh?.();
     } catch (error) {
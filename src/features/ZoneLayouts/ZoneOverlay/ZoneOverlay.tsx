@@ -14,6 +14,8 @@ interface OverlayData {
   };
   /** Zone overlay opacity 0–1. Default 0.25 when unset. */
   opacity?: number;
+  /** Zone shown as hovered while the cursor isn't over another one, e.g. a hotkey's target */
+  highlightedZoneId?: string | null;
 }
 
 interface ZoneOverlayProps {
@@ -49,12 +51,14 @@ function ZoneOverlayComponent({ zone, hoveredZone, zIndex = 1, opacity = DEFAULT
       )}
       style={getZoneStyle()}
     >
-      {/* Zone number displayed in center */}
-      <div className="flex flex-col items-center justify-center text-center">
-        <div className="text-8xl font-bold text-white drop-shadow-lg">
-          {zone.number}
+      {/* Zone number displayed in center, previews of other actions have none */}
+      {zone.number > 0 && (
+        <div className="flex flex-col items-center justify-center text-center">
+          <div className="text-8xl font-bold text-white drop-shadow-lg">
+            {zone.number}
+          </div>
         </div>
-      </div>
+      )}
     </div>
   );
 }
@@ -70,6 +74,9 @@ export function ZoneOverlay() {
   useEffect(() => {
     const unlisten = listen<OverlayData>('overlay-data', (event) => {
       setOverlayData(event.payload);
+      // The cursor from a previous drag says nothing about this one
+      setMousePosition(null);
+      setHoveredZone(null);
     });
 
     return () => {
@@ -177,7 +184,7 @@ export function ZoneOverlay() {
         <ZoneOverlayComponent
           key={zone.id}
           zone={zone}
-          hoveredZone={hoveredZone}
+          hoveredZone={hoveredZone ?? overlayData.highlightedZoneId ?? null}
           zIndex={zoneZIndices.get(zone.id) || 1}
           opacity={overlayData.opacity ?? DEFAULT_OVERLAY_OPACITY}
         />
@@ -1,28 +1,27 @@
-import { LazyStore } from '@tauri-apps/plugin-store';
+import { invoke } from '@tauri-apps/api/core';
 import { Settings } from '../../../src-tauri/bindings/Settings';
+import { SettingChange } from '../../../src-tauri/bindings/SettingChange';
 
 export type SettingsKey = keyof Settings;
 
-export const store = new LazyStore('settings.json');
+export const getSettings = async () => {
+  return await invoke<Settings>('get_settings');
+};
 
 export const setSettings = async (settings: Settings) => {
-  return Promise.all(Object.entries(settings).map(([key, value]) => {
-    return store.set(key, value);
-  }));
+  return await invoke<SettingChange[]>('update_settings', { settings });
 };
 
 export const resetSettings = async () => {
-  return await store.reset();
+  return await invoke<SettingChange[]>('reset_settings');
 };
 
-export const setSetting = async (key: SettingsKey, value: any) => {
-  return await store.set(key, value);
+export const setSetting = async <K extends SettingsKey>(key: K, value: Settings[K]) => {
+  const settings = await getSettings();
+  return await setSettings({ ...settings, [key]: value });
 };
 
-export const getSetting = async (key: SettingsKey) => {
-  return await store.get(key as string);
+export const getSetting = async <K extends SettingsKey>(key: K) => {
+  const settings = await getSettings();
+  return settings[key];
 };
-
-export const getSettings = async () => {
-  return await store.entries();
-};
\ No newline at end of file
@@ -1,14 +1,24 @@
 import { toast as sonnerToast } from 'sonner';
+import { CommandError } from '../../src-tauri/bindings/CommandError';
 
 interface ToastOptions {
   description?: string;
 }
 
+/**
+ * Whether a value is an error returned by a backend command
+ */
+export function isCommandError(error: unknown): error is CommandError {
+  return typeof error === 'object' && error !== null && 'code' in error && 'message' in error;
+}
+
 /**
  * Shows an error toast notification and logs to console
  */
 export function showError(message: string, error?: unknown, options?: ToastOptions): void {
-  const errorMessage = error instanceof Error ? error.message : typeof error === 'string' ? error : undefined;
+  const errorMessage = error instanceof Error || isCommandError(error)
+    ? error.message
+    : typeof error === 'string' ? error : undefined;
   const description = options?.description || errorMessage;
   
   console.error(message, error || '');
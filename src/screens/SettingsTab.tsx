@@ -1,7 +1,14 @@
 
 import { Settings } from '@/features/Settings/Settings';
+import { WindowExclusions } from '@/features/WindowRules/WindowExclusions';
+import { WindowRules } from '@/features/WindowRules/WindowRules';
 
 export const SettingsTab: React.FC = () => {
-  return <Settings />;
+  return (
+    <div className="space-y-6">
+      <Settings />
+      <WindowRules />
+      <WindowExclusions />
+    </div>
+  );
 };
-
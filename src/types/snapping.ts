@@ -45,4 +45,5 @@ export enum LayoutAction {
   TopRightThird = 'top-right-third',
   BottomLeftThird = 'bottom-left-third',
   BottomRightThird = 'bottom-right-third',
+  WindowMode = 'window-mode',
 } 
\ No newline at end of file